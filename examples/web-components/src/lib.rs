@@ -0,0 +1,2 @@
+// the bundle only has to link spux, which exports `defineSpuxElements`
+pub use spux::web_components::define_elements;
@@ -0,0 +1,221 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Exports the Spux loaders as standalone CSS, HTML or SVG, for pages that
+//! aren't built with Leptos, or as animated GIF, APNG or WebP images.
+//!
+//! ```text
+//! spux export circle --color '#112233' --size 24 --format html
+//! spux export circle --size 64 --format gif --fps 30 -o circle.gif
+//! spux stylesheet --size 12 --size 24 -o spux.css
+//! ```
+
+use clap::{Parser, Subcommand, ValueEnum};
+use spux::csp;
+use spux::loader::{self, LoaderDefinition};
+use spux::raster::{ImageFormat, Rasterizer};
+use spux::size::Size;
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process;
+
+#[derive(Parser)]
+#[command(
+    name = "spux",
+    version,
+    about = "Exports the Spux loaders as standalone CSS, HTML, SVG or animated images"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Exports a loader
+    Export {
+        /// The loader to export (see `spux list`)
+        loader: String,
+        /// The color of the loader, in the #hex format
+        #[arg(long, default_value = "#000000")]
+        color: String,
+        /// The size of the loader, by px (e.g 24) or by em (e.g 1.5em)
+        #[arg(long, default_value = "24")]
+        size: Size,
+        /// The duration of one cycle of the animation, by ms
+        #[arg(long)]
+        duration: Option<u32>,
+        /// What the loader is announced as to assistive technologies
+        #[arg(long, default_value = "Loading")]
+        label: String,
+        #[arg(long, value_enum, default_value_t = Format::Html)]
+        format: Format,
+        /// The frame rate of the gif, apng and webp formats
+        #[arg(long, default_value_t = 30)]
+        fps: u32,
+        /// The background color of the gif, apng and webp formats, in the #hex
+        /// format (transparent by default)
+        #[arg(long)]
+        background: Option<String>,
+        /// Writes the loader into a file instead of printing it
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+    /// Lists the loaders that can be exported
+    List,
+    /// Prints the stylesheet of every component, for apps that serve it as a
+    /// file (see `spux::csp`)
+    Stylesheet {
+        /// Adds the classes of a color of the loaders, in the #hex format (can
+        /// be repeated)
+        #[arg(long)]
+        color: Vec<String>,
+        /// Adds the classes of a size of the loaders, by px (e.g 24) or by em
+        /// (e.g 1.5em) (can be repeated)
+        #[arg(long)]
+        size: Vec<Size>,
+        /// Adds the classes of a duration of the loaders, by ms (can be
+        /// repeated)
+        #[arg(long)]
+        duration: Vec<u32>,
+        /// Writes the stylesheet into a file instead of printing it
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// A stylesheet for the markup of the html format
+    Css,
+    /// The markup of the loader, including its <style>
+    Html,
+    /// A standalone SVG document
+    Svg,
+    /// An animated GIF image
+    Gif,
+    /// An animated PNG image
+    Apng,
+    /// An animated (lossless) WebP image
+    #[value(name = "webp")]
+    WebP,
+}
+
+fn main() {
+    match Cli::parse().command {
+        Command::List => {
+            for (name, _) in loader::built_in() {
+                println!("{name}");
+            }
+        }
+        Command::Stylesheet {
+            color,
+            size,
+            duration,
+            output,
+        } => {
+            let colors = color.iter().map(String::as_str).collect::<Vec<_>>();
+            let values = csp::value_stylesheet(&colors, &size, &duration)
+                .unwrap_or_else(|error| exit(&error.to_string()));
+            let stylesheet = format!("{}\n{values}", csp::built_in_stylesheet());
+            match output {
+                Some(path) => {
+                    if let Err(error) = fs::write(&path, stylesheet) {
+                        exit(&format!("Couldn't write to {} ({error})", path.display()));
+                    }
+                }
+                None => {
+                    if let Err(error) = writeln!(io::stdout(), "{stylesheet}") {
+                        exit(&format!("Couldn't print the stylesheet ({error})"));
+                    }
+                }
+            }
+        }
+        Command::Export {
+            loader,
+            color,
+            size,
+            duration,
+            label,
+            format,
+            fps,
+            background,
+            output,
+        } => {
+            let Some((_, definition)) = loader::built_in()
+                .into_iter()
+                .find(|(name, _)| *name == loader)
+            else {
+                exit(&format!(
+                    "There is no loader named {loader} (see `spux list`)"
+                ));
+            };
+
+            let cycle = duration.unwrap_or(definition.default_duration());
+
+            let exported = match format {
+                Format::Css => definition
+                    .stylesheet(&color, size, duration)
+                    .map(String::into_bytes),
+                Format::Html => definition
+                    .html(&color, size, duration, &label)
+                    .map(String::into_bytes),
+                Format::Svg => svg_loader(&definition, &loader)
+                    .render(&color, size, cycle)
+                    .map(String::into_bytes),
+                Format::Gif | Format::Apng | Format::WebP => {
+                    let Size::Px(size) = size else {
+                        exit("Images should be sized by px");
+                    };
+                    let mut rasterizer = Rasterizer::new(svg_loader(&definition, &loader))
+                        .color(color)
+                        .size(size)
+                        .duration(cycle)
+                        .fps(fps);
+                    if let Some(background) = background {
+                        rasterizer = rasterizer.background(background);
+                    }
+                    rasterizer.encode(match format {
+                        Format::Gif => ImageFormat::Gif,
+                        Format::Apng => ImageFormat::Apng,
+                        _ => ImageFormat::WebP,
+                    })
+                }
+            };
+            let mut exported = exported.unwrap_or_else(|error| exit(&error.to_string()));
+
+            match output {
+                Some(path) => {
+                    if let Err(error) = fs::write(&path, exported) {
+                        exit(&format!("Couldn't write to {} ({error})", path.display()));
+                    }
+                }
+                None => {
+                    if matches!(format, Format::Css | Format::Html | Format::Svg) {
+                        exported.push(b'\n');
+                    }
+                    if let Err(error) = io::stdout().write_all(&exported) {
+                        exit(&format!("Couldn't print the loader ({error})"));
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn svg_loader(definition: &LoaderDefinition, loader: &str) -> spux::svg::SvgLoader {
+    match definition.svg_loader() {
+        Some(svg) => svg.clone(),
+        None => exit(&format!("The {loader} loader can't be exported as SVG")),
+    }
+}
+
+fn exit(message: &str) -> ! {
+    eprintln!("Spux Error - {message}");
+    process::exit(1);
+}
@@ -0,0 +1,135 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+mod spux_canvas;
+pub use spux_canvas::SpuxCanvas;
+
+use crate::keyframes::{Length, Transform};
+use crate::svg::{SvgLoader, SvgShape};
+use std::f64::consts::TAU;
+use web_sys::CanvasRenderingContext2d;
+
+/// Where a loader is drawn on a [`SpuxCanvas`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Placement {
+    /// A box of `size` by `size` (by px) at (`x`, `y`) from the top left of
+    /// the canvas.
+    At { x: f64, y: f64, size: f64 },
+    /// Centered on every element that matches the CSS selector, sized to fit
+    /// within the element.
+    Anchor(String),
+}
+
+/// A loader drawn on a [`SpuxCanvas`], from the SVG drawing of a loader
+/// (e.g `spux::spinners::partial_circle_svg()`).
+///
+/// The `color` (#hex) defaults to the color of the canvas's text, and the
+/// `duration` (by ms) defaults to 1400.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CanvasLoader {
+    loader: SvgLoader,
+    placement: Placement,
+    color: Option<&'static str>,
+    duration: u32,
+}
+
+impl CanvasLoader {
+    pub fn at(loader: SvgLoader, x: f64, y: f64, size: f64) -> Self {
+        CanvasLoader::new(loader, Placement::At { x, y, size })
+    }
+
+    pub fn anchored(loader: SvgLoader, selector: impl Into<String>) -> Self {
+        CanvasLoader::new(loader, Placement::Anchor(selector.into()))
+    }
+
+    pub fn new(loader: SvgLoader, placement: Placement) -> Self {
+        CanvasLoader {
+            loader,
+            placement,
+            color: None,
+            duration: 1400,
+        }
+    }
+
+    pub fn color(self, color: &'static str) -> Self {
+        CanvasLoader {
+            color: Some(color),
+            ..self
+        }
+    }
+
+    pub fn duration(self, duration: u32) -> Self {
+        CanvasLoader { duration, ..self }
+    }
+}
+
+// draws one frame of a loader, with the box of the loader centered on
+// `center`, the same way the browser would draw its SVG
+fn draw(
+    context: &CanvasRenderingContext2d,
+    loader: &SvgLoader,
+    color: &str,
+    center: (f64, f64),
+    size: f64,
+    progress: f64,
+) {
+    let frame = loader.frame(progress);
+    let (x, y) = loader.shape().center();
+
+    context.save();
+    _ = context.translate(center.0, center.1);
+    _ = context.scale(size / 100.0, size / 100.0);
+    _ = context.translate(x - 50.0, y - 50.0);
+    for transform in &frame.transforms {
+        match transform {
+            Transform::Rotate(angle) => _ = context.rotate(angle.0.to_radians()),
+            Transform::Scale(scale) => _ = context.scale(*scale, *scale),
+            Transform::TranslateX(Length::Px(length)) => _ = context.translate(*length, 0.0),
+            Transform::TranslateY(Length::Px(length)) => _ = context.translate(0.0, *length),
+            _ => {}
+        }
+    }
+    _ = context.translate(-x, -y);
+
+    context.set_global_alpha(frame.opacity);
+    context.set_fill_style_str(color);
+    context.set_stroke_style_str(color);
+
+    context.begin_path();
+    match loader.shape() {
+        SvgShape::Rect {
+            x,
+            y,
+            width,
+            height,
+        } => context.rect(*x, *y, *width, *height),
+        SvgShape::Circle { cx, cy, r } => {
+            let visible = loader.visible_stroke().unwrap_or(100.0) / 100.0;
+            _ = context.arc(*cx, *cy, *r, 0.0, TAU * visible);
+        }
+        SvgShape::Polygon(points) => {
+            for (index, (x, y)) in points.iter().enumerate() {
+                if index == 0 {
+                    context.move_to(*x, *y);
+                } else {
+                    context.line_to(*x, *y);
+                }
+            }
+            context.close_path();
+        }
+    }
+    if loader.is_filled() {
+        context.fill();
+    }
+    if let Some(width) = loader.stroke_width() {
+        context.set_line_width(width);
+        context.set_line_cap("round");
+        context.set_line_join("round");
+        context.stroke();
+    }
+    context.restore();
+}
@@ -0,0 +1,305 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+use super::{draw, CanvasLoader, Placement};
+use crate::private::resolve_color;
+use leptos::html::Canvas;
+use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{
+    CanvasRenderingContext2d, Element, HtmlCanvasElement, MutationObserver, MutationObserverInit,
+    ResizeObserver,
+};
+
+/// Renders many loaders into a single `<canvas>`, animated by one
+/// `requestAnimationFrame` loop, for pages where thousands of loaders
+/// would otherwise create thousands of elements.
+///
+/// Every loader is drawn from the SVG drawing of a loader, either at a
+/// position on the canvas or centered on the elements matching a selector
+/// (e.g placeholders in the cells of a table). The anchors are only looked
+/// up and measured again once the page changes (e.g it is scrolled or
+/// resized, or elements are added), rather than on every frame.
+///
+/// With a `width` and `height` (by px) the canvas is laid out like an image,
+/// otherwise it covers the viewport (and doesn't take any pointer events)
+/// so that loaders can be anchored anywhere on the page.
+///
+/// # Example Usage
+/// ```
+/// use leptos::prelude::*;
+/// use spux::canvas::{CanvasLoader, SpuxCanvas};
+/// use spux::spinners::partial_circle_svg;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let loaders = vec![
+///         CanvasLoader::at(partial_circle_svg(), 8.0, 8.0, 16.0).color("#000000"),
+///         CanvasLoader::anchored(partial_circle_svg(), ".cell-loading"),
+///     ];
+///
+///     view! {
+///         <SpuxCanvas loaders=loaders />
+///     }
+/// }
+/// ```
+
+#[component]
+pub fn SpuxCanvas(
+    #[prop(into)] loaders: Signal<Vec<CanvasLoader>>,
+    #[prop(optional)] width: Option<u32>,
+    #[prop(optional)] height: Option<u32>,
+    #[prop(default = "Loading")] label: &'static str,
+) -> impl IntoView {
+    // the colors are validated whenever the loaders change, rather than on
+    // every frame
+    Effect::new(move |_| {
+        loaders.with(|loaders| {
+            for loader in loaders {
+                resolve_color(loader.color);
+            }
+        })
+    });
+
+    let canvas = NodeRef::<Canvas>::new();
+
+    Effect::new(move |_| {
+        let Some(canvas) = canvas.get() else {
+            return;
+        };
+        let Some(context) = canvas
+            .get_context("2d")
+            .ok()
+            .flatten()
+            .and_then(|context| context.dyn_into::<CanvasRenderingContext2d>().ok())
+        else {
+            return;
+        };
+        // the anchors are looked up again whenever the loaders change
+        loaders.track();
+
+        // the layout is only measured again once the page has changed: when
+        // the canvas or an anchor is resized, the page is scrolled or resized,
+        // or elements (or their classes and styles) change
+        let layout = Rc::new(RefCell::new(Layout::stale()));
+        let on_change = Closure::<dyn Fn()>::new({
+            let layout = layout.clone();
+            move || layout.borrow_mut().stale = true
+        });
+        let callback = on_change.as_ref().unchecked_ref();
+        let resize_observer = ResizeObserver::new(callback).ok();
+        let mutation_observer = MutationObserver::new(callback).ok();
+        if let (Some(observer), Some(root)) = (&mutation_observer, document().document_element()) {
+            let options = MutationObserverInit::new();
+            options.set_child_list(true);
+            options.set_subtree(true);
+            options.set_attributes(true);
+            _ = observer.observe_with_options(&root, &options);
+        }
+        let window = window();
+        _ = window.add_event_listener_with_callback_and_bool("scroll", callback, true);
+        _ = window.add_event_listener_with_callback("resize", callback);
+
+        // the frame callback schedules itself again, until it is dropped
+        let frame = Rc::new(RefCell::new(None::<Closure<dyn FnMut(f64)>>));
+        let handle = Rc::new(Cell::new(0));
+        *frame.borrow_mut() = Some(Closure::new({
+            let frame = frame.clone();
+            let handle = handle.clone();
+            let resize_observer = resize_observer.clone();
+            move |time: f64| {
+                let mut layout = layout.borrow_mut();
+                if layout.stale {
+                    layout.measure(&canvas, loaders, resize_observer.as_ref());
+                }
+                draw_frame(&context, loaders, &layout, time);
+                drop(layout);
+                if let Some(callback) = frame.borrow().as_ref() {
+                    if let Ok(next) = leptos::prelude::window()
+                        .request_animation_frame(callback.as_ref().unchecked_ref())
+                    {
+                        handle.set(next);
+                    }
+                }
+            }
+        }));
+        if let Some(callback) = frame.borrow().as_ref() {
+            if let Ok(next) = window.request_animation_frame(callback.as_ref().unchecked_ref()) {
+                handle.set(next);
+            }
+        }
+
+        let cleanup =
+            SendWrapper::new((frame, handle, on_change, resize_observer, mutation_observer));
+        on_cleanup(move || {
+            let (frame, handle, on_change, resize_observer, mutation_observer) = cleanup.take();
+            let window = leptos::prelude::window();
+            _ = window.cancel_animation_frame(handle.get());
+            frame.borrow_mut().take();
+            let callback = on_change.as_ref().unchecked_ref();
+            _ = window.remove_event_listener_with_callback_and_bool("scroll", callback, true);
+            _ = window.remove_event_listener_with_callback("resize", callback);
+            if let Some(observer) = resize_observer {
+                observer.disconnect();
+            }
+            if let Some(observer) = mutation_observer {
+                observer.disconnect();
+            }
+        });
+    });
+
+    let style = match (width, height) {
+        (Some(width), Some(height)) => {
+            format!("display: block; width: {width}px; height: {height}px")
+        }
+        _ => "position: fixed; inset: 0; width: 100vw; height: 100vh; pointer-events: none"
+            .to_string(),
+    };
+
+    view! {
+        <canvas node_ref=canvas
+            class="spux-canvas"
+            role="status"
+            aria-label=label
+            style=style
+        ></canvas>
+    }
+}
+
+// the measurements that the frames are drawn from, see `Layout::measure`
+struct Layout {
+    stale: bool,
+    width: f64,
+    height: f64,
+    text_color: String,
+    // the elements matched by the selectors of the loaders, which are
+    // observed for resizes
+    anchors: Vec<Element>,
+    // the boxes (center and size) of the anchors, by loader
+    boxes: Vec<Vec<((f64, f64), f64)>>,
+}
+
+impl Layout {
+    fn stale() -> Self {
+        Layout {
+            stale: true,
+            width: 0.0,
+            height: 0.0,
+            text_color: "#000000".to_string(),
+            anchors: Vec::new(),
+            boxes: Vec::new(),
+        }
+    }
+
+    // measures the canvas, its text color and the anchors of the loaders,
+    // which is the style and layout work that isn't done on every frame
+    fn measure(
+        &mut self,
+        canvas: &HtmlCanvasElement,
+        loaders: Signal<Vec<CanvasLoader>>,
+        resize_observer: Option<&ResizeObserver>,
+    ) {
+        let ratio = window().device_pixel_ratio();
+        let bounds = canvas.get_bounding_client_rect();
+
+        // the canvas is kept at the resolution of the screen, so it stays crisp
+        let (width, height) = (
+            (bounds.width() * ratio) as u32,
+            (bounds.height() * ratio) as u32,
+        );
+        if canvas.width() != width || canvas.height() != height {
+            canvas.set_width(width);
+            canvas.set_height(height);
+        }
+        self.width = bounds.width();
+        self.height = bounds.height();
+
+        if let Some(color) = window()
+            .get_computed_style(canvas)
+            .ok()
+            .flatten()
+            .and_then(|style| style.get_property_value("color").ok())
+        {
+            self.text_color = color;
+        }
+
+        let mut anchors = Vec::new();
+        self.boxes = loaders.with_untracked(|loaders| {
+            loaders
+                .iter()
+                .map(|loader| {
+                    let Placement::Anchor(selector) = &loader.placement else {
+                        return Vec::new();
+                    };
+                    let Ok(matched) = document().query_selector_all(selector) else {
+                        return Vec::new();
+                    };
+                    (0..matched.length())
+                        .filter_map(|index| matched.item(index))
+                        .filter_map(|anchor| anchor.dyn_into::<Element>().ok())
+                        .filter_map(|anchor| {
+                            let rect = anchor.get_bounding_client_rect();
+                            anchors.push(anchor);
+                            let size = rect.width().min(rect.height());
+                            let center = (
+                                rect.left() - bounds.left() + rect.width() / 2.0,
+                                rect.top() - bounds.top() + rect.height() / 2.0,
+                            );
+                            (size > 0.0).then_some((center, size))
+                        })
+                        .collect()
+                })
+                .collect()
+        });
+
+        // observing an element reports its size right away, so the anchors
+        // are only observed again once they are different elements
+        if let Some(observer) = resize_observer {
+            if anchors != self.anchors {
+                observer.disconnect();
+                observer.observe(canvas);
+                for anchor in &anchors {
+                    observer.observe(anchor);
+                }
+            }
+        }
+        self.anchors = anchors;
+        self.stale = false;
+    }
+}
+
+fn draw_frame(
+    context: &CanvasRenderingContext2d,
+    loaders: Signal<Vec<CanvasLoader>>,
+    layout: &Layout,
+    time: f64,
+) {
+    let ratio = window().device_pixel_ratio();
+    _ = context.set_transform(ratio, 0.0, 0.0, ratio, 0.0, 0.0);
+    context.clear_rect(0.0, 0.0, layout.width, layout.height);
+
+    loaders.with_untracked(|loaders| {
+        for (index, loader) in loaders.iter().enumerate() {
+            let color = loader.color.unwrap_or(&layout.text_color);
+            let progress = time / loader.duration.max(1) as f64;
+            match &loader.placement {
+                Placement::At { x, y, size } => {
+                    let center = (x + size / 2.0, y + size / 2.0);
+                    draw(context, &loader.loader, color, center, *size, progress);
+                }
+                Placement::Anchor(_) => {
+                    for (center, size) in layout.boxes.get(index).into_iter().flatten() {
+                        draw(context, &loader.loader, color, *center, *size, progress);
+                    }
+                }
+            }
+        }
+    });
+}
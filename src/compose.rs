@@ -0,0 +1,13 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+mod container;
+pub use container::Compose;
+pub(crate) use container::STYLE;
+
+mod layer;
+pub use layer::Layer;
@@ -0,0 +1,85 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+use crate::private::loader_style;
+use crate::size::Size;
+use leptos::prelude::*;
+
+// the delay of a layer is handed down through a custom property. inline
+// delays (e.g the staggered cells of a grid) still take precedence
+pub(crate) const STYLE: &str = "
+    .spux-compose {
+        position: relative;
+        flex-shrink: 0;
+    }
+
+    .spux-compose .spux-compose-layer {
+        position: absolute;
+        top: 0px;
+        right: 0px;
+        bottom: 0px;
+        left: 0px;
+        display: flex;
+        align-items: center;
+        justify-content: center;
+    }
+
+    .spux-compose .spux-compose-layer * {
+        animation-delay: var(--spux-layer-delay, 0ms);
+    }
+";
+
+/// Stacks several loaders on a shared center to combine them into a
+/// single loader.
+///
+/// Takes in a required prop for size (by px, or by em to render it inline),
+/// which is the size of the combined loader, while `label` (announced to
+/// assistive technologies) defaults to "Loading". Each loader is wrapped in a
+/// `Layer` which can scale it and offset its animation, and is hidden from
+/// assistive technologies so that the combined loader is announced once.
+///
+/// # Example Usage
+/// ```
+/// use leptos::prelude::*;
+/// use spux::compose::{Compose, Layer};
+/// use spux::pulsers::FilledCircle;
+/// use spux::spinners::PartialCircle;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <Compose size=32>
+///             <Layer>
+///                 <PartialCircle color="#000000" size=28 />
+///             </Layer>
+///             <Layer scale=0.4 delay=-700>
+///                 <FilledCircle color="#000000" size=28 />
+///             </Layer>
+///         </Compose>
+///     }
+/// }
+/// ```
+
+#[component]
+pub fn Compose(
+    #[prop(into)] size: Size,
+    #[prop(default = "Loading")] label: &'static str,
+    children: Children,
+) -> impl IntoView {
+    view! {
+        {loader_style(STYLE.to_string())}
+        <span class="spux-compose"
+            role="status"
+            aria-label=label
+            style:display=size.display()
+            style:vertical-align=size.vertical_align()
+            style:width=size.to_string()
+            style:height=size.to_string()
+        >
+            {children()}
+        </span>
+    }
+}
@@ -0,0 +1,48 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+use leptos::prelude::*;
+
+/// Wraps a single loader inside of a `Compose`, centering it on the
+/// shared center of the other layers.
+///
+/// `scale` (defaults to 1.0) scales the loader, while `delay` (by ms,
+/// defaults to 0) offsets its animation. A negative `delay` starts the
+/// animation part way through, which shifts its phase without waiting.
+///
+/// # Example Usage
+/// ```
+/// use leptos::prelude::*;
+/// use spux::compose::{Compose, Layer};
+/// use spux::spinners::{Square, FilledSquare};
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <Compose size=24>
+///             <Layer><Square color="#000000" size=16 /></Layer>
+///             <Layer scale=0.5 delay=-350><FilledSquare color="#000000" size=16 /></Layer>
+///         </Compose>
+///     }
+/// }
+/// ```
+
+#[component]
+pub fn Layer(
+    #[prop(default = 1.0)] scale: f64,
+    #[prop(default = 0)] delay: i32,
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <span class="spux-compose-layer"
+            aria-hidden="true"
+            style:transform=format!("scale({scale})")
+            style:--spux-layer-delay=format!("{delay}ms")
+        >
+            {children()}
+        </span>
+    }
+}
@@ -0,0 +1,320 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+/*!
+ Rendering loaders into markup and CSS, without any framework.
+
+ [`render`] validates a [`LoaderConfig`] and renders the loader it describes
+ into its markup and the stylesheet that animates it, which is what every
+ framework the loaders are used from (and server-side templates) builds on.
+ The other components are rendered the same way from their own config (e.g
+ `spux::grids::GridConfig` or `spux::progress::LinearProgressConfig`), see
+ [`Render`].
+ Like the [`loader`](crate::loader), [`svg`](crate::svg) and
+ [`keyframes`](crate::keyframes) modules it is built from, it doesn't depend
+ on Leptos.
+
+```
+use spux::core::{render, LoaderConfig};
+use spux::loader::LoaderDefinition;
+
+let dot = LoaderDefinition::new(
+    "brand-dot",
+    ".brand-dot { width: var(--spux-size); height: var(--spux-size); }",
+    "@keyframes brand-dot{0%{opacity:1}100%{opacity:0}}",
+);
+
+let rendered = render(LoaderConfig::new(dot, 24).color("#000000").label("Saving")).unwrap();
+assert!(rendered.html.starts_with("<span class=\"spux-loader\" role=\"status\" aria-label=\"Saving\""));
+assert!(rendered.css.contains("@keyframes brand-dot"));
+assert_eq!(rendered.class_names, ["spux-loader", "brand-dot"]);
+```
+*/
+use crate::csp;
+use crate::errors::ValidationError;
+use crate::loader::LoaderDefinition;
+use crate::size::Size;
+use crate::svg::Renderer;
+use crate::types::Validation;
+
+/// What to render a loader with, see [`render`].
+///
+/// The `color` (#hex) defaults to the color of the surrounding text, the
+/// `duration` (by ms) to the duration of the loader and the `label` to
+/// "Loading".
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoaderConfig {
+    definition: LoaderDefinition,
+    color: Option<String>,
+    size: Size,
+    duration: Option<u32>,
+    label: String,
+    renderer: Option<Renderer>,
+    class_styled: bool,
+}
+
+impl LoaderConfig {
+    pub fn new(definition: LoaderDefinition, size: impl Into<Size>) -> Self {
+        LoaderConfig {
+            definition,
+            color: None,
+            size: size.into(),
+            duration: None,
+            label: "Loading".to_string(),
+            renderer: None,
+            class_styled: false,
+        }
+    }
+
+    pub fn color(self, color: impl Into<String>) -> Self {
+        LoaderConfig {
+            color: Some(color.into()),
+            ..self
+        }
+    }
+
+    pub fn duration(self, duration: u32) -> Self {
+        LoaderConfig {
+            duration: Some(duration),
+            ..self
+        }
+    }
+
+    pub fn label(self, label: impl Into<String>) -> Self {
+        LoaderConfig {
+            label: label.into(),
+            ..self
+        }
+    }
+
+    /// Renders the loader as elements or as SVG, instead of with the default
+    /// [`Renderer`]. Rendering a loader without an SVG drawing as SVG is an
+    /// error, while the default renders it as elements.
+    pub fn renderer(self, renderer: Renderer) -> Self {
+        LoaderConfig {
+            renderer: Some(renderer),
+            ..self
+        }
+    }
+
+    /// Styles the loader with classes rather than with inline styles, for a
+    /// Content-Security-Policy that blocks `style` attributes. The rules of
+    /// the classes are part of the `css` of the loader, see the
+    /// [`csp`](crate::csp) module for serving them as a file instead.
+    ///
+    /// ```
+    /// use spux::core::{render, LoaderConfig};
+    /// use spux::loader::LoaderDefinition;
+    ///
+    /// let dot = LoaderDefinition::new(
+    ///     "brand-dot",
+    ///     ".brand-dot { width: var(--spux-size); }",
+    ///     "@keyframes brand-dot{0%{opacity:1}100%{opacity:0}}",
+    /// )
+    /// .elements(3);
+    ///
+    /// let rendered = render(LoaderConfig::new(dot, 24).color("#112233").class_styled()).unwrap();
+    /// assert!(!rendered.html.contains("style=\""));
+    /// assert!(rendered.html.contains("class=\"spux-loader spux-color-112233 spux-size-24px spux-duration-1400\""));
+    /// assert!(rendered.css.contains(".brand-dot:nth-child(3){--spux-index:2}"));
+    /// assert!(rendered.css.contains(".spux-size-24px{--spux-size:24px;"));
+    /// ```
+    pub fn class_styled(self) -> Self {
+        LoaderConfig {
+            class_styled: true,
+            ..self
+        }
+    }
+
+    pub fn definition(&self) -> &LoaderDefinition {
+        &self.definition
+    }
+}
+
+/// A loader rendered by [`render`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderedLoader {
+    /// The markup of the loader, without its stylesheet.
+    pub html: String,
+    /// The stylesheet of the loader, which is the same for every instance of
+    /// it (the instances are sized and colored by their inline style), along
+    /// with the rules of its classes when it's
+    /// [`class_styled`](LoaderConfig::class_styled).
+    pub css: String,
+    /// The classes used by the markup, starting from the outermost element.
+    pub class_names: Vec<String>,
+    // the tag, attributes (besides the style), inline style and inner markup
+    // of the outermost element, for the framework adapters that render that
+    // element themselves
+    pub(crate) tag: String,
+    pub(crate) attributes: Vec<(String, String)>,
+    pub(crate) style: String,
+    pub(crate) content: String,
+}
+
+impl RenderedLoader {
+    // a component rendered into its outermost element, which is styled by
+    // `style` (unless it's empty) and wraps `content`
+    pub(crate) fn new(
+        tag: &str,
+        attributes: &[(&str, &str)],
+        style: String,
+        content: String,
+        css: &str,
+        class_names: &[&str],
+    ) -> Self {
+        let mut html_attributes = attributes.to_vec();
+        if !style.is_empty() {
+            html_attributes.push(("style", &style));
+        }
+        RenderedLoader {
+            html: element(tag, &html_attributes, &content),
+            css: css.to_string(),
+            class_names: class_names.iter().map(ToString::to_string).collect(),
+            tag: tag.to_string(),
+            attributes: attributes
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            style,
+            content,
+        }
+    }
+}
+
+/// A config that [`render`] renders a loader from, i.e a [`LoaderConfig`] or
+/// the config of one of the other components (e.g
+/// `spux::progress::LinearProgressConfig`).
+pub trait Render {
+    /// Validates the config and renders the loader it describes.
+    fn render(self) -> Result<RenderedLoader, ValidationError>;
+}
+
+/// Validates the config and renders the loader it describes.
+pub fn render(config: impl Render) -> Result<RenderedLoader, ValidationError> {
+    config.render()
+}
+
+impl Render for LoaderConfig {
+    fn render(self) -> Result<RenderedLoader, ValidationError> {
+        render_definition(self)
+    }
+}
+
+fn render_definition(config: LoaderConfig) -> Result<RenderedLoader, ValidationError> {
+    let LoaderConfig {
+        definition,
+        color,
+        size,
+        duration,
+        label,
+        renderer,
+        class_styled,
+    } = config;
+
+    let color = loader_color(color.as_deref())?;
+    size.validate()?;
+    let duration = duration.unwrap_or(definition.default_duration());
+
+    // the web animations renderer animates the same markup, from a browser
+    let svg = match (renderer.unwrap_or_default(), definition.svg_loader()) {
+        (Renderer::Css, _) => None,
+        (_, None) if renderer.is_some() => {
+            return Err(ValidationError::new(
+                "This loader has no SVG drawing, render it with Renderer::Css",
+            ));
+        }
+        (_, svg) => svg,
+    };
+
+    let (mut css, scaled, class, content) = match svg {
+        Some(svg) => (
+            svg.css()?,
+            false,
+            svg.keyframes().name().to_string(),
+            format!(
+                "<svg viewBox=\"0 0 100 100\" width=\"100%\" height=\"100%\" aria-hidden=\"true\">{}</svg>",
+                svg.body()
+            ),
+        ),
+        None => (
+            definition.css(),
+            true,
+            definition.class().to_string(),
+            (0..definition.element_count())
+                .map(|index| {
+                    if class_styled {
+                        format!("<span class=\"{}\"></span>", definition.class())
+                    } else {
+                        format!(
+                            "<span class=\"{}\" style=\"--spux-index: {index}\"></span>",
+                            definition.class()
+                        )
+                    }
+                })
+                .collect(),
+        ),
+    };
+
+    // a loader styled by classes carries the rules of its classes instead of
+    // an inline style, including the ones that index its elements
+    let mut class_names = vec!["spux-loader".to_string()];
+    let style = if class_styled {
+        if svg.is_none() {
+            css = format!("{css}\n{}", definition.class_css());
+        }
+        for (value_class, rule) in csp::value_classes(color, size, duration) {
+            css = format!("{css}\n{rule}");
+            class_names.push(value_class);
+        }
+        String::new()
+    } else {
+        definition.root_style(color, size, duration, scaled)
+    };
+    let root_class = class_names.join(" ");
+    class_names.push(class);
+
+    let class_names = class_names.iter().map(String::as_str).collect::<Vec<_>>();
+    Ok(RenderedLoader::new(
+        "span",
+        &[
+            ("class", &root_class),
+            ("role", "status"),
+            ("aria-label", &label),
+        ],
+        style,
+        content,
+        &css,
+        &class_names,
+    ))
+}
+
+// validates the optional #hex color of a loader, falling back to the color
+// of the surrounding text when none was given
+pub(crate) fn loader_color(color: Option<&str>) -> Result<&str, ValidationError> {
+    match color {
+        Some(color) => color.validate_color_code().map(|_| color),
+        None => Ok("currentColor"),
+    }
+}
+
+// the markup of an element, with its attributes escaped
+pub(crate) fn element(tag: &str, attributes: &[(&str, &str)], content: &str) -> String {
+    let attributes = attributes
+        .iter()
+        .map(|(name, value)| format!(" {name}=\"{}\"", escape(value)))
+        .collect::<String>();
+    format!("<{tag}{attributes}>{content}</{tag}>")
+}
+
+// escapes text for the content or attributes of html
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
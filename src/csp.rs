@@ -0,0 +1,304 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+/*!
+ Using the loaders with a strict Content-Security-Policy.
+
+ Every component renders the stylesheet it's animated by into a `<style>`,
+ which a `style-src` policy without `'unsafe-inline'` blocks. There are two
+ ways around it:
+
+ - With the `nonce` feature, every `<style>` that a component renders (and
+   the one of the [`head_styles`](crate::head_styles) feature) carries the
+   nonce of the render, from `leptos::nonce::use_nonce` (see
+   `leptos::nonce::provide_nonce`). The policy then only has to allow
+   `'nonce-…'`.
+ - With an external stylesheet, the components don't render any `<style>` and
+   only reference the classes of a stylesheet that is served as a file
+   (`style-src 'self'`). The stylesheet of every built-in component is generated
+   with `spux stylesheet > spux.css` (with the `cli` feature) or
+   [`built_in_stylesheet`], and the one of custom loaders with
+   [`stylesheet`]. The components below [`provide_external_stylesheet`] then
+   only render their markup, which is sized and colored by CSS custom
+   properties:
+
+```rust,ignore
+use leptos::prelude::*;
+use spux::csp::provide_external_stylesheet;
+use spux::pulsers::Circle;
+
+#[component]
+fn App() -> impl IntoView {
+    provide_external_stylesheet();
+
+    view! {
+        <link rel="stylesheet" href="/spux.css" />
+        <Circle size=12 />
+    }
+}
+```
+
+ The external stylesheet covers the loaders declared with `spux_loader!`,
+ both as elements and as SVG, along with every other component (the progress
+ bars, skeletons, text loaders, grids, 3D loaders, `Compose` and the
+ overlays), none of which render their `<style>` below
+ [`provide_external_stylesheet`]. The stylesheet of a `Morph` depends on its
+ shapes, so only the default ones are included; the one of other shapes is
+ the `css` of its `MorphConfig` (see [`crate::core::render`]).
+
+ Neither a nonce nor an external stylesheet covers `style` attributes, so in
+ both cases the loaders declared with `spux_loader!` are styled by classes
+ rather than by an inline style (see
+ [`class_styled`](crate::core::LoaderConfig::class_styled)). The color,
+ size and duration of a loader are classes (e.g `spux-size-24px`), whose
+ rules are rendered along with its `<style>`, or are added to the external
+ stylesheet with [`value_stylesheet`] (e.g `spux stylesheet --size 24`). The
+ other components, and the loaders rendered with `Renderer::WebAnimations`,
+ still set their size and color (and e.g the value of a progress bar) in a
+ `style` attribute, which the policy has to allow with
+ `style-src-attr 'unsafe-inline'`.
+*/
+use crate::errors::ValidationError;
+use crate::loader::{self, LoaderDefinition};
+use crate::size::Size;
+use crate::types::Validation;
+
+/// The stylesheet of the given loaders, for rendering them as elements and
+/// as SVG, with every stylesheet included once. Along with the stylesheets
+/// of the loaders, it includes the classes of their default color and
+/// duration, while the ones of their sizes (and of other colors and
+/// durations) are added with [`value_stylesheet`].
+///
+/// ```
+/// use spux::csp::stylesheet;
+/// use spux::loader::LoaderDefinition;
+///
+/// let dot = LoaderDefinition::new(
+///     "brand-dot",
+///     ".brand-dot { width: var(--spux-size); }",
+///     "@keyframes brand-dot{0%{opacity:1}100%{opacity:0}}",
+/// );
+///
+/// let css = stylesheet([&dot, &dot]).unwrap();
+/// assert_eq!(css.matches("@keyframes brand-dot").count(), 1);
+/// assert!(css.contains(".spux-duration-1400{--spux-duration:1400ms}"));
+/// ```
+pub fn stylesheet<'a>(
+    definitions: impl IntoIterator<Item = &'a LoaderDefinition>,
+) -> Result<String, ValidationError> {
+    let mut stylesheets = vec![color_class("currentColor").1];
+    for definition in definitions {
+        let mut css = vec![
+            definition.css(),
+            definition.class_css(),
+            duration_class(definition.default_duration()).1,
+        ];
+        if let Some(svg) = definition.svg_loader() {
+            css.push(svg.css()?);
+        }
+        for css in css {
+            if !stylesheets.contains(&css) {
+                stylesheets.push(css);
+            }
+        }
+    }
+    Ok(stylesheets.join("\n"))
+}
+
+/// The rules of the classes that color, size and time the loaders styled by
+/// classes (see [`class_styled`](crate::core::LoaderConfig::class_styled)),
+/// for the given colors (#hex), sizes and durations (by ms).
+///
+/// ```
+/// use spux::csp::value_stylesheet;
+/// use spux::size::Size;
+///
+/// let css = value_stylesheet(&["#112233"], &[Size::Px(24), Size::Em(1.5)], &[900]).unwrap();
+/// assert!(css.contains(".spux-color-112233{--spux-color:#112233}"));
+/// assert!(css.contains(".spux-size-1_5em{--spux-size:1.5em;"));
+/// assert!(css.contains(".spux-duration-900{--spux-duration:900ms}"));
+///
+/// assert!(value_stylesheet(&["black"], &[], &[]).is_err());
+/// assert!(value_stylesheet(&[], &[Size::Px(0)], &[]).is_err());
+/// ```
+pub fn value_stylesheet(
+    colors: &[&str],
+    sizes: &[Size],
+    durations: &[u32],
+) -> Result<String, ValidationError> {
+    let mut rules = Vec::new();
+    for color in colors {
+        color.validate_color_code()?;
+        rules.push(color_class(color).1);
+    }
+    for size in sizes {
+        size.validate()?;
+        rules.push(size_class(*size).1);
+    }
+    for duration in durations {
+        rules.push(duration_class(*duration).1);
+    }
+    Ok(rules.join("\n"))
+}
+
+// the classes of a loader that is styled by classes, along with their rules,
+// which take the place of its inline style (see `LoaderDefinition::root_style`)
+pub(crate) fn value_classes(color: &str, size: Size, duration: u32) -> [(String, String); 3] {
+    [
+        color_class(color),
+        size_class(size),
+        duration_class(duration),
+    ]
+}
+
+fn color_class(color: &str) -> (String, String) {
+    let class = match color.strip_prefix('#') {
+        Some(hex) => format!("spux-color-{hex}"),
+        None => "spux-color-current".to_string(),
+    };
+    let rule = format!(".{class}{{--spux-color:{color}}}");
+    (class, rule)
+}
+
+// the box of a loader is enlarged by the scale of its definition, which the
+// rules of the definition set (see `LoaderDefinition::class_css`)
+fn size_class(size: Size) -> (String, String) {
+    let class = format!("spux-size-{}", size.to_string().replace('.', "_"));
+    let box_size = "calc(var(--spux-size) * var(--spux-scale, 1))";
+    let rule = format!(
+        ".{class}{{--spux-size:{size};display:{};vertical-align:{};flex-shrink:0;\
+         width:{box_size};height:{box_size}}}",
+        size.display(),
+        size.vertical_align()
+    );
+    (class, rule)
+}
+
+fn duration_class(duration: u32) -> (String, String) {
+    let class = format!("spux-duration-{duration}");
+    let rule = format!(".{class}{{--spux-duration:{duration}ms}}");
+    (class, rule)
+}
+
+/// The stylesheet of every built-in component of the enabled features, i.e
+/// the `spux.css` that `spux stylesheet` prints (besides the classes of the
+/// sizes, colors and durations it's given). Along with the loaders of
+/// the `spinners` and `pulsers`, it covers the progress bars, skeletons,
+/// text loaders, grids (with every effect), the default `Morph`, the 3D
+/// loaders, `Compose` and the overlays.
+///
+/// ```
+/// use spux::csp::built_in_stylesheet;
+///
+/// let css = built_in_stylesheet();
+/// # #[cfg(feature = "pulsers")]
+/// assert!(css.contains("@keyframes spux-circle"));
+/// # #[cfg(feature = "progress")]
+/// assert!(css.contains(".spux-linear-progress"));
+/// ```
+pub fn built_in_stylesheet() -> String {
+    let loaders = loader::built_in();
+    let mut stylesheets = vec![stylesheet(loaders.iter().map(|(_, definition)| definition))
+        .expect("the built-in loaders should be valid")];
+    for css in component_stylesheets() {
+        if !stylesheets.contains(&css) {
+            stylesheets.push(css);
+        }
+    }
+    stylesheets.join("\n")
+}
+
+// the stylesheets of the built-in components that aren't declared as loaders,
+// which are the same for every instance (besides the shapes of a `Morph`)
+#[allow(unused_mut, clippy::vec_init_then_push)]
+fn component_stylesheets() -> Vec<String> {
+    let mut stylesheets = Vec::new();
+
+    #[cfg(feature = "progress")]
+    {
+        use crate::progress::{CircularProgressConfig, LinearProgressConfig};
+
+        stylesheets.push(component_css(LinearProgressConfig::new(4)));
+        stylesheets.push(component_css(CircularProgressConfig::new(24)));
+    }
+    #[cfg(feature = "skeletons")]
+    stylesheets.push(component_css(crate::skeletons::SkeletonRectConfig::new(
+        "#000000", 16,
+    )));
+    #[cfg(feature = "text")]
+    {
+        use crate::text::{EllipsisConfig, ScrambleConfig, ShimmerTextConfig, TypewriterConfig};
+
+        stylesheets.push(component_css(EllipsisConfig::new()));
+        stylesheets.push(component_css(ShimmerTextConfig::new()));
+        stylesheets.push(component_css(TypewriterConfig::new()));
+        stylesheets.push(component_css(ScrambleConfig::new()));
+    }
+    #[cfg(feature = "grids")]
+    {
+        use crate::grids::{GridConfig, GridEffect};
+
+        for effect in [
+            GridEffect::Scale,
+            GridEffect::Fade,
+            GridEffect::Rotate,
+            GridEffect::Fold,
+        ] {
+            stylesheets.push(component_css(GridConfig::new(16).effect(effect)));
+        }
+    }
+    #[cfg(feature = "morphers")]
+    stylesheets.push(component_css(crate::morphers::MorphConfig::new(16)));
+    #[cfg(feature = "three_d")]
+    {
+        use crate::three_d::{CardFlipConfig, CubeConfig, FlipSquareConfig};
+
+        stylesheets.push(component_css(FlipSquareConfig::new(16)));
+        stylesheets.push(component_css(CubeConfig::new(16)));
+        stylesheets.push(component_css(CardFlipConfig::new(16)));
+    }
+    #[cfg(feature = "compose")]
+    stylesheets.push(crate::compose::STYLE.to_string());
+    #[cfg(feature = "overlay")]
+    {
+        stylesheets.push(crate::overlay::SPUX_OVERLAY_STYLE.to_string());
+        stylesheets.push(crate::overlay::LOADING_OVERLAY_STYLE.to_string());
+    }
+
+    stylesheets
+}
+
+#[cfg(any(
+    feature = "progress",
+    feature = "skeletons",
+    feature = "text",
+    feature = "grids",
+    feature = "morphers",
+    feature = "three_d"
+))]
+fn component_css(config: impl crate::core::Render) -> String {
+    config
+        .render()
+        .expect("the built-in components should be valid")
+        .css
+}
+
+/// Marks the components of a render as styled by an external stylesheet, see
+/// [`provide_external_stylesheet`].
+#[cfg(feature = "leptos")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExternalStylesheet;
+
+/// Stops the components rendered below it from rendering their `<style>`, as
+/// they are styled by an external stylesheet, see the
+/// [module documentation](self).
+#[cfg(feature = "leptos")]
+pub fn provide_external_stylesheet() {
+    use leptos::prelude::provide_context;
+
+    provide_context(ExternalStylesheet);
+}
@@ -0,0 +1,52 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+/*!
+ The Spux loaders as Dioxus components.
+
+ With the `dioxus` feature, the `spinners` and `pulsers` come as Dioxus
+ components with the same props as their Leptos counterparts, rendered from
+ the same definitions (see the [`core`](crate::core) module). Leptos can be
+ left out with `default-features = false`.
+
+```rust,ignore
+use dioxus::prelude::*;
+use spux::dioxus::pulsers::Circle;
+
+fn App() -> Element {
+    rsx! {
+        Circle { color: "#000000", size: 10u32 }
+    }
+}
+```
+
+ Custom loaders declared with `spux_loader!` are rendered by [`SpuxLoader`]
+ from their definition.
+*/
+
+// declares a Dioxus component for a built-in loader, from its definition
+#[cfg(any(feature = "spinners", feature = "pulsers"))]
+macro_rules! dioxus_loader {
+    ($(#[$meta:meta])* $name:ident => $definition:path) => {
+        $(#[$meta])*
+        #[allow(non_snake_case)]
+        pub fn $name(props: $crate::dioxus::LoaderProps) -> ::dioxus::prelude::Element {
+            $crate::dioxus::render($definition(), props)
+        }
+    };
+}
+
+mod spux_loader;
+#[cfg(any(feature = "spinners", feature = "pulsers"))]
+use spux_loader::render;
+pub use spux_loader::{LoaderProps, SpuxLoader, SpuxLoaderProps};
+
+#[cfg(feature = "spinners")]
+pub mod spinners;
+
+#[cfg(feature = "pulsers")]
+pub mod pulsers;
@@ -0,0 +1,32 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! The [`pulsers`](crate::pulsers) as Dioxus components.
+
+use crate::pulsers::{
+    circle_definition, diamond_definition, filled_circle_definition, filled_diamond_definition,
+};
+
+dioxus_loader! {
+    /// Renders a circle and pulsates it immediately on the screen.
+    Circle => circle_definition
+}
+
+dioxus_loader! {
+    /// Renders a filled circle and pulsates it immediately on the screen.
+    FilledCircle => filled_circle_definition
+}
+
+dioxus_loader! {
+    /// Renders a diamond and pulsates it immediately on the screen.
+    Diamond => diamond_definition
+}
+
+dioxus_loader! {
+    /// Renders a filled diamond and pulsates it immediately on the screen.
+    FilledDiamond => filled_diamond_definition
+}
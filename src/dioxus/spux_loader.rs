@@ -0,0 +1,93 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+use crate::loader::LoaderDefinition;
+use crate::private::{loader_config, render_config};
+use crate::size::Size;
+use crate::svg::Renderer;
+use dioxus::prelude::*;
+
+/// The props of every Spux loader, which are the same as with Leptos: a
+/// required `size` (by px, or by em to render it inline), an optional
+/// `color` (#hex) that defaults to the color of the surrounding text, an
+/// optional `duration` (by ms), an optional `label` (defaults to "Loading")
+/// and an optional `renderer`.
+#[derive(Props, Clone, PartialEq)]
+pub struct LoaderProps {
+    #[props(default, into)]
+    pub color: Option<String>,
+    #[props(into)]
+    pub size: Size,
+    #[props(default)]
+    pub duration: Option<u32>,
+    #[props(default = "Loading".to_string(), into)]
+    pub label: String,
+    #[props(default)]
+    pub renderer: Option<Renderer>,
+}
+
+/// The props of [`SpuxLoader`], see [`LoaderProps`].
+#[derive(Props, Clone, PartialEq)]
+pub struct SpuxLoaderProps {
+    pub definition: LoaderDefinition,
+    #[props(default, into)]
+    pub color: Option<String>,
+    #[props(into)]
+    pub size: Size,
+    #[props(default)]
+    pub duration: Option<u32>,
+    #[props(default = "Loading".to_string(), into)]
+    pub label: String,
+    #[props(default)]
+    pub renderer: Option<Renderer>,
+}
+
+/// Renders any loader from its definition, e.g a custom loader declared
+/// with `spux_loader!` (and named after a function).
+#[allow(non_snake_case)]
+pub fn SpuxLoader(props: SpuxLoaderProps) -> Element {
+    let SpuxLoaderProps {
+        definition,
+        color,
+        size,
+        duration,
+        label,
+        renderer,
+    } = props;
+    render(
+        definition,
+        LoaderProps {
+            color,
+            size,
+            duration,
+            label,
+            renderer,
+        },
+    )
+}
+
+// renders a loader from the markup and CSS that `core::render` renders it into
+pub(crate) fn render(definition: LoaderDefinition, props: LoaderProps) -> Element {
+    let rendered = render_config(loader_config(
+        definition,
+        props.color.as_deref(),
+        props.size,
+        props.duration,
+        &props.label,
+        props.renderer,
+    ));
+
+    rsx! {
+        style { dangerous_inner_html: rendered.css }
+        span {
+            class: "spux-loader",
+            role: "status",
+            aria_label: props.label,
+            style: rendered.style,
+            dangerous_inner_html: rendered.content,
+        }
+    }
+}
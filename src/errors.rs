@@ -8,6 +8,8 @@
 use std::error::Error;
 use std::fmt;
 
+/// The error returned when a value given to Spux (e.g a color or keyframes)
+/// is invalid.
 #[derive(Debug)]
 pub struct ValidationError {
     message: String,
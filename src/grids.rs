@@ -0,0 +1,198 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+mod grid;
+#[cfg(feature = "leptos")]
+pub use grid::Grid;
+pub use grid::GridConfig;
+
+mod cube_grid;
+pub use cube_grid::cube_grid_config;
+#[cfg(feature = "leptos")]
+pub use cube_grid::CubeGrid;
+
+mod folding_cube;
+pub use folding_cube::folding_cube_config;
+#[cfg(feature = "leptos")]
+pub use folding_cube::FoldingCube;
+
+/// The order in which the cells of a grid start animating.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Stagger {
+    /// A wave that travels from the top left cell to the bottom right cell.
+    #[default]
+    Diagonal,
+    /// Clockwise from the top left cell, spiralling in towards the center.
+    Spiral,
+    /// A random order that stays the same for the same seed, so that server
+    /// rendered and hydrated grids match.
+    Seeded(u64),
+    /// Row by row, from the top row to the bottom row.
+    Rows,
+}
+
+impl Stagger {
+    /// Returns a delay for every cell (in row order) as a fraction of the
+    /// stagger, between 0.0 for the first cells and 1.0 for the last cells.
+    ///
+    /// # Example Usage
+    /// ```
+    /// use spux::grids::Stagger;
+    ///
+    /// // the spiral visits the top left, top right, bottom right and
+    /// // then the bottom left cell of a 2x2 grid
+    /// let offsets = Stagger::Spiral.offsets(2, 2);
+    /// assert_eq!(offsets, vec![0.0, 1.0 / 3.0, 1.0, 2.0 / 3.0]);
+    /// ```
+    pub fn offsets(&self, rows: u32, cols: u32) -> Vec<f64> {
+        let (rows, cols) = (rows as usize, cols as usize);
+        let count = rows * cols;
+        let fraction = |order: usize, last: usize| match last {
+            0 => 0.0,
+            last => order as f64 / last as f64,
+        };
+
+        match self {
+            Stagger::Diagonal => (0..count)
+                .map(|index| fraction(index / cols + index % cols, rows + cols - 2))
+                .collect(),
+            Stagger::Rows => (0..count)
+                .map(|index| fraction(index / cols, rows.saturating_sub(1)))
+                .collect(),
+            Stagger::Spiral => {
+                let mut offsets = vec![0.0; count];
+                for (order, index) in spiral_order(rows, cols).into_iter().enumerate() {
+                    offsets[index] = fraction(order, count.saturating_sub(1));
+                }
+                offsets
+            }
+            Stagger::Seeded(seed) => (0..count)
+                .map(|index| {
+                    let hash = split_mix(seed.wrapping_add(index as u64));
+                    (hash >> 11) as f64 / (1u64 << 53) as f64
+                })
+                .collect(),
+        }
+    }
+}
+
+/// How each cell of a grid is animated.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GridEffect {
+    /// The cells shrink away and grow back.
+    #[default]
+    Scale,
+    /// The cells fade out and back in.
+    Fade,
+    /// The cells rotate while shrinking and growing.
+    Rotate,
+    /// The cells fold in and out of view around their edges.
+    Fold,
+}
+
+impl GridEffect {
+    fn name(&self) -> &'static str {
+        match self {
+            GridEffect::Scale => "spux-grid-scale",
+            GridEffect::Fade => "spux-grid-fade",
+            GridEffect::Rotate => "spux-grid-rotate",
+            GridEffect::Fold => "spux-grid-fold",
+        }
+    }
+
+    fn keyframes(&self) -> &'static str {
+        match self {
+            GridEffect::Scale => {
+                "
+                @keyframes spux-grid-scale {
+                    0%, 70%, 100% {
+                        transform: scale(1);
+                    }
+                    35% {
+                        transform: scale(0);
+                    }
+                }"
+            }
+            GridEffect::Fade => {
+                "
+                @keyframes spux-grid-fade {
+                    0%, 100% {
+                        opacity: 1;
+                    }
+                    50% {
+                        opacity: 0.2;
+                    }
+                }"
+            }
+            GridEffect::Rotate => {
+                "
+                @keyframes spux-grid-rotate {
+                    0% {
+                        transform: rotate(0deg) scale(1);
+                    }
+                    50% {
+                        transform: rotate(90deg) scale(0.5);
+                    }
+                    100% {
+                        transform: rotate(180deg) scale(1);
+                    }
+                }"
+            }
+            GridEffect::Fold => {
+                "
+                @keyframes spux-grid-fold {
+                    0%, 10% {
+                        transform: perspective(140px) rotateX(-180deg);
+                        opacity: 0;
+                    }
+                    25%, 75% {
+                        transform: perspective(140px) rotateX(0deg);
+                        opacity: 1;
+                    }
+                    90%, 100% {
+                        transform: perspective(140px) rotateY(180deg);
+                        opacity: 0;
+                    }
+                }"
+            }
+        }
+    }
+}
+
+// returns the cell indices (in row order) visited by a clockwise spiral
+// that starts at the top left cell
+fn spiral_order(rows: usize, cols: usize) -> Vec<usize> {
+    let mut order = Vec::with_capacity(rows * cols);
+    let (mut top, mut left) = (0, 0);
+    let (mut bottom, mut right) = (rows, cols);
+
+    while top < bottom && left < right {
+        order.extend((left..right).map(|col| top * cols + col));
+        order.extend((top + 1..bottom).map(|row| row * cols + right - 1));
+        if top + 1 < bottom {
+            order.extend((left..right - 1).rev().map(|col| (bottom - 1) * cols + col));
+        }
+        if left + 1 < right {
+            order.extend((top + 1..bottom - 1).rev().map(|row| row * cols + left));
+        }
+        top += 1;
+        left += 1;
+        bottom -= 1;
+        right -= 1;
+    }
+
+    order
+}
+
+// a small and well distributed hash (splitmix64), so that seeded
+// staggers don't need a random number generator dependency
+fn split_mix(seed: u64) -> u64 {
+    let mut hash = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    hash ^ (hash >> 31)
+}
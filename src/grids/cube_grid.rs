@@ -0,0 +1,63 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+#[cfg(feature = "leptos")]
+use super::grid::render_grid;
+use super::{GridConfig, GridEffect, Stagger};
+use crate::size::Size;
+#[cfg(feature = "leptos")]
+use leptos::prelude::*;
+
+/// The config of a 3x3 grid of cubes that shrink away and grow back in a
+/// diagonal wave, as rendered by the `CubeGrid` component (see the
+/// [`core`](crate::core) module), for a size (by px, or by em to render it
+/// inline).
+///
+/// # Example Usage
+/// ```
+/// use spux::core::render;
+/// use spux::grids::cube_grid_config;
+///
+/// let rendered = render(cube_grid_config(18).color("#000000")).unwrap();
+/// assert_eq!(rendered.html.matches("background-color: #000000").count(), 9);
+/// ```
+pub fn cube_grid_config(size: impl Into<Size>) -> GridConfig {
+    GridConfig::new(size.into().divided(3))
+        .rows(3)
+        .cols(3)
+        .gap(0)
+        .pattern(Stagger::Diagonal)
+        .effect(GridEffect::Scale)
+}
+
+/// Renders a 3x3 grid of cubes that shrink away and grow back in a
+/// diagonal wave.
+///
+/// Takes in required props for size (by px, or by em to render it inline) and
+/// an optional color (#hex), which defaults to the color of the surrounding text,
+/// while `label` (announced to assistive technologies) defaults to "Loading".
+///
+/// # Example Usage
+/// ```
+/// use leptos::prelude::*;
+/// use spux::grids::CubeGrid;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <CubeGrid color="#000000" size=18 />
+///     }
+/// }
+/// ```
+#[cfg(feature = "leptos")]
+#[component]
+pub fn CubeGrid(
+    #[prop(default = None, into)] color: Option<&'static str>,
+    #[prop(into)] size: Size,
+    #[prop(default = "Loading")] label: &'static str,
+) -> impl IntoView {
+    render_grid(cube_grid_config(size), color, label)
+}
@@ -0,0 +1,64 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+#[cfg(feature = "leptos")]
+use super::grid::render_grid;
+use super::{GridConfig, GridEffect, Stagger};
+use crate::size::Size;
+#[cfg(feature = "leptos")]
+use leptos::prelude::*;
+
+/// The config of a cube split into 4 cells that fold in and out of view, as
+/// rendered by the `FoldingCube` component (see the [`core`](crate::core)
+/// module), for a size (by px, or by em to render it inline).
+///
+/// # Example Usage
+/// ```
+/// use spux::core::render;
+/// use spux::grids::folding_cube_config;
+///
+/// let rendered = render(folding_cube_config(18).color("#000000")).unwrap();
+/// assert_eq!(rendered.html.matches("background-color: #000000").count(), 4);
+/// ```
+pub fn folding_cube_config(size: impl Into<Size>) -> GridConfig {
+    GridConfig::new(size.into().divided(2))
+        .rows(2)
+        .cols(2)
+        .gap(0)
+        .pattern(Stagger::Spiral)
+        .effect(GridEffect::Fold)
+        .duration(2400)
+        .rotation(45)
+}
+
+/// Renders a cube split into 4 cells that fold in and out of view one
+/// after the other.
+///
+/// Takes in required props for size (by px, or by em to render it inline) and
+/// an optional color (#hex), which defaults to the color of the surrounding text,
+/// while `label` (announced to assistive technologies) defaults to "Loading".
+///
+/// # Example Usage
+/// ```
+/// use leptos::prelude::*;
+/// use spux::grids::FoldingCube;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <FoldingCube color="#000000" size=16 />
+///     }
+/// }
+/// ```
+#[cfg(feature = "leptos")]
+#[component]
+pub fn FoldingCube(
+    #[prop(default = None, into)] color: Option<&'static str>,
+    #[prop(into)] size: Size,
+    #[prop(default = "Loading")] label: &'static str,
+) -> impl IntoView {
+    render_grid(folding_cube_config(size), color, label)
+}
@@ -0,0 +1,265 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+use super::{GridEffect, Stagger};
+use crate::core::{element, loader_color, Render, RenderedLoader};
+use crate::errors::ValidationError;
+#[cfg(feature = "leptos")]
+use crate::private::{loader_style, render_config};
+use crate::size::Size;
+#[cfg(feature = "leptos")]
+use leptos::prelude::*;
+
+/// A grid of cells that animate one after the other, as rendered by the
+/// `Grid` component, see the [`core`](crate::core) module.
+///
+/// Takes in a size (of each cell by px, or by em to render it inline). The
+/// optional color (#hex) defaults to the color of the surrounding text,
+/// `rows` and `cols` (at least 1) default to 3, `gap` (by px) defaults to 2,
+/// `duration` (by ms) defaults to 1400 and `label` defaults to "Loading".
+///
+/// # Example Usage
+/// ```
+/// use spux::core::render;
+/// use spux::grids::{GridConfig, GridEffect, Stagger};
+///
+/// let grid = GridConfig::new(6)
+///     .rows(4)
+///     .cols(4)
+///     .pattern(Stagger::Spiral)
+///     .effect(GridEffect::Fade);
+/// let rendered = render(grid).unwrap();
+/// assert_eq!(rendered.html.matches("class=\"spux-grid-fade\"").count(), 16);
+/// assert!(rendered.css.contains("@keyframes spux-grid-fade"));
+///
+/// assert!(render(GridConfig::new(6).rows(0)).is_err());
+/// assert!(render(GridConfig::new(0)).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridConfig {
+    color: Option<String>,
+    size: Size,
+    rows: u32,
+    cols: u32,
+    gap: u32,
+    pattern: Stagger,
+    effect: GridEffect,
+    duration: u32,
+    label: String,
+    rotation: Option<u32>,
+}
+
+impl GridConfig {
+    pub fn new(size: impl Into<Size>) -> Self {
+        GridConfig {
+            color: None,
+            size: size.into(),
+            rows: 3,
+            cols: 3,
+            gap: 2,
+            pattern: Stagger::default(),
+            effect: GridEffect::default(),
+            duration: 1400,
+            label: "Loading".to_string(),
+            rotation: None,
+        }
+    }
+
+    pub fn color(self, color: impl Into<String>) -> Self {
+        GridConfig {
+            color: Some(color.into()),
+            ..self
+        }
+    }
+
+    pub fn rows(self, rows: u32) -> Self {
+        GridConfig { rows, ..self }
+    }
+
+    pub fn cols(self, cols: u32) -> Self {
+        GridConfig { cols, ..self }
+    }
+
+    pub fn gap(self, gap: u32) -> Self {
+        GridConfig { gap, ..self }
+    }
+
+    /// The order in which the cells start animating.
+    pub fn pattern(self, pattern: Stagger) -> Self {
+        GridConfig { pattern, ..self }
+    }
+
+    /// How each cell is animated.
+    pub fn effect(self, effect: GridEffect) -> Self {
+        GridConfig { effect, ..self }
+    }
+
+    pub fn duration(self, duration: u32) -> Self {
+        GridConfig { duration, ..self }
+    }
+
+    pub fn label(self, label: impl Into<String>) -> Self {
+        GridConfig {
+            label: label.into(),
+            ..self
+        }
+    }
+
+    // rotates the whole grid (by deg), e.g to stand it on a corner
+    pub(crate) fn rotation(self, rotation: u32) -> Self {
+        GridConfig {
+            rotation: Some(rotation),
+            ..self
+        }
+    }
+}
+
+impl Render for GridConfig {
+    fn render(self) -> Result<RenderedLoader, ValidationError> {
+        let color = loader_color(self.color.as_deref())?;
+        self.size.validate()?;
+        // a grid needs at least one cell to animate
+        if self.rows == 0 || self.cols == 0 {
+            return Err(ValidationError::new(
+                "Grid should have at least one row and one column",
+            ));
+        }
+
+        let name = self.effect.name();
+        let css = format!(
+            "
+            .{name} {{
+                animation-name: {name};
+                animation-timing-function: ease-in-out;
+                animation-fill-mode: both;
+                animation-iteration-count: infinite;
+            }}
+
+            {}
+        ",
+            self.effect.keyframes()
+        );
+
+        // the stagger is spread over half of the animation so that the
+        // last cells start while the first ones are still animating
+        let spread = self.duration as f64 / 2.0;
+        let size = self.size;
+        let content = self
+            .pattern
+            .offsets(self.rows, self.cols)
+            .into_iter()
+            .map(|offset| {
+                let style = format!(
+                    "width: {size}; height: {size}; background-color: {color}; \
+                     animation-duration: {}ms; animation-delay: {}ms",
+                    self.duration,
+                    (offset * spread).round()
+                );
+                element("span", &[("class", name), ("style", &style)], "")
+            })
+            .collect();
+
+        let display = if size.is_inline() {
+            "inline-grid"
+        } else {
+            "grid"
+        };
+        let mut style = format!(
+            "display: {display}; vertical-align: {}; grid-template-columns: repeat({}, {size}); gap: {}px",
+            size.vertical_align(),
+            self.cols,
+            self.gap
+        );
+        if let Some(rotation) = self.rotation {
+            style += &format!("; transform: rotate({rotation}deg)");
+        }
+
+        Ok(RenderedLoader::new(
+            "span",
+            &[
+                ("class", "spux-grid"),
+                ("role", "status"),
+                ("aria-label", &self.label),
+            ],
+            style,
+            content,
+            &css,
+            &["spux-grid", name],
+        ))
+    }
+}
+
+/// Renders a grid of filled cells that animate one after the other.
+///
+/// Takes in required props for size (of each cell by px, or by em to render it
+/// inline) and an optional color (#hex), which defaults to the color of the
+/// surrounding text.
+/// `rows` and `cols` (at least 1) default to 3, `gap` (by px) defaults to 2,
+/// `duration` (by ms) defaults to 1400 and `label` (announced to assistive
+/// technologies) defaults to "Loading". The `pattern` decides the order in which the cells
+/// start animating and the `effect` decides how each cell is animated.
+///
+/// # Example Usage
+/// ```
+/// use leptos::prelude::*;
+/// use spux::grids::{Grid, GridEffect, Stagger};
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <Grid color="#000000" size=6 rows=4 cols=4
+///             pattern=Stagger::Spiral
+///             effect=GridEffect::Fade
+///         />
+///     }
+/// }
+/// ```
+#[cfg(feature = "leptos")]
+#[component]
+pub fn Grid(
+    #[prop(default = None, into)] color: Option<&'static str>,
+    #[prop(into)] size: Size,
+    #[prop(default = 3)] rows: u32,
+    #[prop(default = 3)] cols: u32,
+    #[prop(default = 2)] gap: u32,
+    #[prop(optional)] pattern: Stagger,
+    #[prop(optional)] effect: GridEffect,
+    #[prop(default = 1400)] duration: u32,
+    #[prop(default = "Loading")] label: &'static str,
+) -> impl IntoView {
+    let config = GridConfig::new(size)
+        .rows(rows)
+        .cols(cols)
+        .gap(gap)
+        .pattern(pattern)
+        .effect(effect)
+        .duration(duration);
+    render_grid(config, color, label)
+}
+
+// renders any grid (e.g a `CubeGrid`) from its config
+#[cfg(feature = "leptos")]
+pub(crate) fn render_grid(
+    config: GridConfig,
+    color: Option<&'static str>,
+    label: &'static str,
+) -> impl IntoView {
+    let config = config.label(label);
+    let rendered = render_config(match color {
+        Some(color) => config.color(color),
+        None => config,
+    });
+
+    view! {
+        {loader_style(rendered.css)}
+        <span class="spux-grid"
+            role="status"
+            aria-label=label
+            style=rendered.style
+            inner_html=rendered.content
+        ></span>
+    }
+}
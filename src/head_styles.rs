@@ -0,0 +1,111 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+/*!
+ Gathering the stylesheets of the loaders into the `<head>`.
+
+ By default, every loader renders its `<style>` right before itself, which
+ repeats the same keyframes all over server-rendered pages. With the
+ `head_styles` feature and [`provide_spux_styles`] (next to
+ `leptos_meta::provide_meta_context`), the stylesheets of all the loaders of
+ a render are gathered into one `<style id="spux">` in the `<head>` through
+ `leptos_meta`, with every stylesheet included once.
+
+```rust,ignore
+use leptos::prelude::*;
+use leptos_meta::provide_meta_context;
+use spux::head_styles::provide_spux_styles;
+use spux::pulsers::Circle;
+
+#[component]
+fn App() -> impl IntoView {
+    provide_meta_context();
+    provide_spux_styles();
+
+    view! {
+        <Circle size=12 />
+        <Circle size=24 />
+    }
+}
+```
+
+ The sheet is written along with the `<head>`, so it covers every loader
+ that is created by then, including the fallbacks of `Suspense` when
+ streaming. Loaders that are only created afterwards (e.g inside of a
+ `Suspense` that resolves later, or after navigating) reuse the stylesheets
+ of the sheet and only fall back to their own `<style>` for the others. The
+ same happens while hydrating, so the sheet that was rendered on the server
+ is hydrated rather than injected again.
+*/
+use crate::private::style_nonce;
+use leptos::prelude::*;
+use leptos_meta::{MetaContext, ServerMetaContext, Style};
+use std::sync::{Arc, Mutex};
+
+/// The stylesheets of the loaders of a render, see [`provide_spux_styles`].
+#[derive(Debug, Clone, Default)]
+pub struct SpuxStyles {
+    collected: Arc<Mutex<Collected>>,
+}
+
+#[derive(Debug, Default)]
+struct Collected {
+    stylesheets: Vec<String>,
+    registered: bool,
+    written: bool,
+}
+
+impl SpuxStyles {
+    // the content of the <style id="spux">, after which loaders can't be
+    // added to it anymore
+    fn write(&self) -> String {
+        let mut collected = self.collected.lock().expect("the styles were poisoned");
+        collected.written = true;
+        collected.stylesheets.join("\n")
+    }
+}
+
+/// Gathers the stylesheets of the loaders rendered below it into the
+/// `<head>`, see the [module documentation](self).
+pub fn provide_spux_styles() {
+    if use_context::<SpuxStyles>().is_none() {
+        provide_context(SpuxStyles::default());
+    }
+}
+
+// the stylesheet of a loader, which is either gathered into the <head> or
+// rendered right before the loader
+pub(crate) fn render_style(css: String) -> AnyView {
+    let Some(styles) = use_context::<SpuxStyles>().filter(|_| has_head()) else {
+        return view! { <style nonce=style_nonce()>{css}</style> }.into_any();
+    };
+
+    let mut collected = styles.collected.lock().expect("the styles were poisoned");
+    if collected.stylesheets.contains(&css) {
+        return ().into_any();
+    }
+    if collected.written {
+        return view! { <style nonce=style_nonce()>{css}</style> }.into_any();
+    }
+    collected.stylesheets.push(css);
+    if collected.registered {
+        return ().into_any();
+    }
+    collected.registered = true;
+    drop(collected);
+
+    // the content is only read once the sheet is rendered (or hydrated), by
+    // which time every loader of the page has been created
+    view! { <Style id="spux">{move || styles.write()}</Style> }.into_any()
+}
+
+// whether `leptos_meta` can render into the <head>, which it only does
+// while rendering on the server with a `ServerMetaContext`
+fn has_head() -> bool {
+    use_context::<ServerMetaContext>().is_some()
+        || (cfg!(target_arch = "wasm32") && use_context::<MetaContext>().is_some())
+}
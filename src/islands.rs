@@ -0,0 +1,175 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Using the loaders with the Leptos `islands` feature.
+//!
+//! In islands mode, only the components declared with `#[island]` are
+//! hydrated, and everything else is rendered on the server only. No Spux
+//! component is an island, so using one never turns its parent into an island:
+//! a loader outside of an island is rendered as plain HTML and CSS (animated by
+//! CSS alone) that ships no WASM and isn't hydrated. Enable the `islands`
+//! feature of Spux along with the one of Leptos.
+//!
+//! The examples below render pages the way a server does in islands mode
+//! (they only run with the `ssr` feature), starting with every component of
+//! Spux besides the overlays (see below). None of them is rendered as an
+//! island or leaves anything for hydration to pick up, i.e no hydration
+//! markers and no scripts.
+//!
+#![cfg_attr(
+    all(
+        feature = "ssr",
+        feature = "spinners",
+        feature = "pulsers",
+        feature = "progress",
+        feature = "skeletons",
+        feature = "text",
+        feature = "grids",
+        feature = "morphers",
+        feature = "three_d",
+        feature = "compose",
+        feature = "canvas",
+        feature = "svg"
+    ),
+    doc = "```"
+)]
+#![cfg_attr(
+    not(all(
+        feature = "ssr",
+        feature = "spinners",
+        feature = "pulsers",
+        feature = "progress",
+        feature = "skeletons",
+        feature = "text",
+        feature = "grids",
+        feature = "morphers",
+        feature = "three_d",
+        feature = "compose",
+        feature = "canvas",
+        feature = "svg"
+    )),
+    doc = "```ignore"
+)]
+//! use leptos::prelude::*;
+//! use spux::canvas::{CanvasLoader, SpuxCanvas};
+//! use spux::compose::{Compose, Layer};
+//! use spux::grids::{CubeGrid, FoldingCube, Grid};
+//! use spux::morphers::Morph;
+//! use spux::progress::{CircularProgress, LinearProgress};
+//! use spux::pulsers::{Circle, Diamond, FilledCircle, FilledDiamond};
+//! use spux::skeletons::{SkeletonAvatar, SkeletonCard, SkeletonRect, SkeletonTable, SkeletonText};
+//! use spux::spinners::{partial_circle_svg, FilledSquare, PartialCircle, Square, Triangle};
+//! use spux::text::{Ellipsis, Scramble, ShimmerText, Typewriter};
+//! use spux::three_d::{CardFlip, Cube, FlipSquare};
+//!
+//! #[component]
+//! fn Article() -> impl IntoView {
+//!     let loaders = vec![CanvasLoader::at(partial_circle_svg(), 8.0, 8.0, 16.0)];
+//!
+//!     view! {
+//!         <article>
+//!             <Square size=16 />
+//!             <FilledSquare size=16 />
+//!             <PartialCircle size=16 />
+//!             <Triangle size=16 />
+//!             <Circle size=12 />
+//!             <FilledCircle size=12 />
+//!             <Diamond size=12 />
+//!             <FilledDiamond size=12 />
+//!             <LinearProgress color="#000000" size=4 />
+//!             <CircularProgress color="#000000" size=24 />
+//!             <SkeletonRect color="#e0e0e0" height=40 />
+//!             <SkeletonAvatar color="#e0e0e0" size=40 />
+//!             <SkeletonText color="#e0e0e0" size=16 />
+//!             <SkeletonCard color="#e0e0e0" size=16 />
+//!             <SkeletonTable color="#e0e0e0" size=16 />
+//!             <Ellipsis text="Saving" />
+//!             <ShimmerText text="Preparing" />
+//!             <Typewriter text="Thinking" />
+//!             <Scramble text="Decoding" />
+//!             <Grid size=6 rows=3 cols=3 />
+//!             <CubeGrid size=6 />
+//!             <FoldingCube size=6 />
+//!             <Morph size=16 />
+//!             <FlipSquare size=16 />
+//!             <Cube size=16 />
+//!             <CardFlip size=16 />
+//!             <Compose size=24>
+//!                 <Layer><Circle size=24 /></Layer>
+//!             </Compose>
+//!             <SpuxCanvas loaders=loaders width=32 height=32 />
+//!         </article>
+//!     }
+//! }
+//!
+//! # let context = hydration_context::SsrSharedContext::new_islands();
+//! # let owner = Owner::new_root(Some(std::sync::Arc::new(context)));
+//! # owner.set();
+//! let html = view! { <Article /> }.to_html();
+//! assert!(html.contains("class=\"spux-loader"));
+//! assert!(!html.contains("<leptos-island"));
+//! assert!(!html.contains("<!>"));
+//! assert!(!html.contains("<script"));
+//! assert!(!html.contains("data-hk"));
+//! ```
+//!
+//! Loaders are also used inside of islands, and as `Suspense` fallbacks, the
+//! same way as anywhere else. A loader passed to an island as its `children`
+//! stays server-only, while one rendered by the island itself is hydrated with
+//! it (which only walks its markup, as loaders don't have any state).
+//!
+#![cfg_attr(all(feature = "ssr", feature = "pulsers"), doc = "```")]
+#![cfg_attr(not(all(feature = "ssr", feature = "pulsers")), doc = "```ignore")]
+//! use leptos::prelude::*;
+//! use spux::pulsers::Circle;
+//!
+//! #[island]
+//! fn Comments(children: Children) -> impl IntoView {
+//!     view! {
+//!         <section>
+//!             {children()}
+//!             <Suspense fallback=|| view! { <Circle size=12 /> }>"Comments"</Suspense>
+//!         </section>
+//!     }
+//! }
+//!
+//! #[component]
+//! fn Article() -> impl IntoView {
+//!     view! {
+//!         <Comments>
+//!             <Circle size=24 />
+//!         </Comments>
+//!     }
+//! }
+//!
+//! # // islands render their children with the context of the request
+//! # let owner = Owner::new_root(Some(std::sync::Arc::new(hydration_context::SsrSharedContext::new())));
+//! # owner.set();
+//! let html = view! { <Article /> }.to_html();
+//! assert_eq!(html.matches("<leptos-island").count(), 1);
+//! assert!(html.contains("<leptos-children><style"));
+//! assert!(html.contains("12px"));
+//! ```
+//!
+//! Components that are animated from Rust rather than by CSS only animate
+//! inside of an island, where they run in the browser. Outside of one,
+//! `Typewriter` and `Scramble` render their text fully revealed, and loaders
+//! with the Web Animations renderer are animated by CSS as SVG:
+//!
+#![cfg_attr(all(feature = "ssr", feature = "text"), doc = "```")]
+#![cfg_attr(not(all(feature = "ssr", feature = "text")), doc = "```ignore")]
+//! use leptos::prelude::*;
+//! use spux::text::Typewriter;
+//!
+//! let html = view! { <Typewriter color="#000000" text="Thinking" /> }.to_html();
+//! assert!(html.contains("<span aria-hidden=\"true\">Thinking</span>"));
+//! ```
+//!
+//! The canvas and the overlays follow the page in the browser (its elements,
+//! focus or a `NodeRef`), so they belong to the island that owns that state.
+//! The overlays are only rendered in the browser (through a `Portal`), and on
+//! the server leave nothing but the placeholder (`<!>`) the island hydrates.
@@ -0,0 +1,655 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+/*!
+ A typed builder for CSS keyframes.
+
+ Instead of hand writing `@keyframes` inside of `format!` strings (with every
+ brace doubled), keyframes are built from typed steps, properties and units,
+ validated and then serialized into minified CSS.
+
+```
+use spux::keyframes::{Keyframes, Length, Unit};
+
+let half = Length::spux_size().divided(2.0);
+let keyframes = Keyframes::new("spux-spin")
+    .at(0.pct(), |step| step.rotate(45.deg()))
+    .at(50.pct(), |step| step.rotate(270.deg()).margin_right(half))
+    .at(100.pct(), |step| step.rotate(405.deg()));
+
+assert_eq!(
+    keyframes.to_css().unwrap(),
+    "@keyframes spux-spin{0%{transform:rotate(45deg)}50%{margin-right:calc(var(--spux-size) * 0.5);transform:rotate(270deg)}100%{transform:rotate(405deg)}}"
+);
+```
+*/
+use crate::errors::ValidationError;
+use crate::size::Size;
+use std::fmt;
+
+/// A keyframe offset, or a percentage of a length (e.g `50.pct()`).
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Percent(pub f64);
+
+/// An angle by deg (e.g `45.deg()`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Angle(pub f64);
+
+/// A CSS length.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Length {
+    Px(f64),
+    Em(f64),
+    Percent(f64),
+    /// A custom property (e.g `--spux-size`) multiplied by a factor.
+    Var(&'static str, f64),
+}
+
+impl Length {
+    /// The `--spux-size` custom property that every loader declared with
+    /// `spux_loader!` is sized by.
+    pub fn spux_size() -> Length {
+        Length::var("--spux-size")
+    }
+
+    /// A custom property, e.g `Length::var("--my-size")`.
+    pub fn var(name: &'static str) -> Length {
+        Length::Var(name, 1.0)
+    }
+
+    /// Multiplies the length.
+    pub fn times(self, factor: f64) -> Length {
+        match self {
+            Length::Px(value) => Length::Px(value * factor),
+            Length::Em(value) => Length::Em(value * factor),
+            Length::Percent(value) => Length::Percent(value * factor),
+            Length::Var(name, value) => Length::Var(name, value * factor),
+        }
+    }
+
+    /// Divides the length.
+    pub fn divided(self, by: f64) -> Length {
+        self.times(1.0 / by)
+    }
+
+    // lengths of different units can't be mixed without a layout
+    fn mix(&self, other: &Length, amount: f64) -> Option<Length> {
+        match (self, other) {
+            (Length::Px(from), Length::Px(to)) => Some(Length::Px(mix(*from, *to, amount))),
+            (Length::Em(from), Length::Em(to)) => Some(Length::Em(mix(*from, *to, amount))),
+            (Length::Percent(from), Length::Percent(to)) => {
+                Some(Length::Percent(mix(*from, *to, amount)))
+            }
+            (Length::Var(name, from), Length::Var(other, to)) if name == other => {
+                Some(Length::Var(name, mix(*from, *to, amount)))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl From<Size> for Length {
+    fn from(size: Size) -> Self {
+        match size {
+            Size::Px(size) => Length::Px(size as f64),
+            Size::Em(size) => Length::Em(size),
+        }
+    }
+}
+
+impl From<Percent> for Length {
+    fn from(percent: Percent) -> Self {
+        Length::Percent(percent.0)
+    }
+}
+
+impl fmt::Display for Length {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Length::Px(value) => write!(f, "{value}px"),
+            Length::Em(value) => write!(f, "{value}em"),
+            Length::Percent(value) => write!(f, "{value}%"),
+            Length::Var(name, factor) if *factor == 1.0 => write!(f, "var({name})"),
+            Length::Var(name, factor) => write!(f, "calc(var({name}) * {factor})"),
+        }
+    }
+}
+
+/// Turns plain numbers into typed CSS units, e.g `10.px()` or `45.deg()`.
+pub trait Unit {
+    fn px(self) -> Length;
+    fn em(self) -> Length;
+    fn pct(self) -> Percent;
+    fn deg(self) -> Angle;
+}
+
+macro_rules! impl_unit {
+    ($($number:ty),*) => {
+        $(
+            impl Unit for $number {
+                fn px(self) -> Length {
+                    Length::Px(self as f64)
+                }
+
+                fn em(self) -> Length {
+                    Length::Em(self as f64)
+                }
+
+                fn pct(self) -> Percent {
+                    Percent(self as f64)
+                }
+
+                fn deg(self) -> Angle {
+                    Angle(self as f64)
+                }
+            }
+        )*
+    };
+}
+
+impl_unit!(i32, u32, f32, f64);
+
+/// A single function of the `transform` property.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Transform {
+    Rotate(Angle),
+    RotateX(Angle),
+    RotateY(Angle),
+    Scale(f64),
+    TranslateX(Length),
+    TranslateY(Length),
+    Perspective(Length),
+}
+
+impl fmt::Display for Transform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Transform::Rotate(angle) => write!(f, "rotate({}deg)", angle.0),
+            Transform::RotateX(angle) => write!(f, "rotateX({}deg)", angle.0),
+            Transform::RotateY(angle) => write!(f, "rotateY({}deg)", angle.0),
+            Transform::Scale(scale) => write!(f, "scale({scale})"),
+            Transform::TranslateX(length) => write!(f, "translateX({length})"),
+            Transform::TranslateY(length) => write!(f, "translateY({length})"),
+            Transform::Perspective(length) => write!(f, "perspective({length})"),
+        }
+    }
+}
+
+impl Transform {
+    fn mix(&self, other: &Transform, amount: f64) -> Option<Transform> {
+        let angle = |from: &Angle, to: &Angle| Angle(mix(from.0, to.0, amount));
+        match (self, other) {
+            (Transform::Rotate(from), Transform::Rotate(to)) => {
+                Some(Transform::Rotate(angle(from, to)))
+            }
+            (Transform::RotateX(from), Transform::RotateX(to)) => {
+                Some(Transform::RotateX(angle(from, to)))
+            }
+            (Transform::RotateY(from), Transform::RotateY(to)) => {
+                Some(Transform::RotateY(angle(from, to)))
+            }
+            (Transform::Scale(from), Transform::Scale(to)) => {
+                Some(Transform::Scale(mix(*from, *to, amount)))
+            }
+            (Transform::TranslateX(from), Transform::TranslateX(to)) => {
+                from.mix(to, amount).map(Transform::TranslateX)
+            }
+            (Transform::TranslateY(from), Transform::TranslateY(to)) => {
+                from.mix(to, amount).map(Transform::TranslateY)
+            }
+            (Transform::Perspective(from), Transform::Perspective(to)) => {
+                from.mix(to, amount).map(Transform::Perspective)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The CSS properties that can be animated by a keyframe step (besides
+/// `transform`, which is built from `Transform`s).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Property {
+    Width,
+    Height,
+    MarginTop,
+    MarginRight,
+    MarginBottom,
+    MarginLeft,
+    Opacity,
+    BorderRadius,
+    ClipPath,
+}
+
+impl Property {
+    /// The name of the property as written in CSS.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Property::Width => "width",
+            Property::Height => "height",
+            Property::MarginTop => "margin-top",
+            Property::MarginRight => "margin-right",
+            Property::MarginBottom => "margin-bottom",
+            Property::MarginLeft => "margin-left",
+            Property::Opacity => "opacity",
+            Property::BorderRadius => "border-radius",
+            Property::ClipPath => "clip-path",
+        }
+    }
+}
+
+/// The value of a `Property`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Length(Length),
+    Number(f64),
+    /// A polygon, where each point is a percentage of the element's (x, y).
+    Polygon(Vec<(f64, f64)>),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Length(length) => write!(f, "{length}"),
+            Value::Number(number) => write!(f, "{number}"),
+            Value::Polygon(points) => {
+                let points = points
+                    .iter()
+                    .map(|(x, y)| format!("{x}% {y}%"))
+                    .collect::<Vec<_>>()
+                    .join(",");
+                write!(f, "polygon({points})")
+            }
+        }
+    }
+}
+
+impl Value {
+    fn mix(&self, other: &Value, amount: f64) -> Option<Value> {
+        match (self, other) {
+            (Value::Length(from), Value::Length(to)) => from.mix(to, amount).map(Value::Length),
+            (Value::Number(from), Value::Number(to)) => {
+                Some(Value::Number(mix(*from, *to, amount)))
+            }
+            (Value::Polygon(from), Value::Polygon(to)) if from.len() == to.len() => {
+                Some(Value::Polygon(
+                    from.iter()
+                        .zip(to)
+                        .map(|(from, to)| (mix(from.0, to.0, amount), mix(from.1, to.1, amount)))
+                        .collect(),
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The properties of a single step of the keyframes.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct KeyframeStep {
+    properties: Vec<(Property, Value)>,
+    transforms: Vec<Transform>,
+}
+
+impl KeyframeStep {
+    fn set(mut self, property: Property, value: Value) -> Self {
+        self.properties
+            .retain(|(existing, _)| *existing != property);
+        self.properties.push((property, value));
+        self
+    }
+
+    fn transform(mut self, transform: Transform) -> Self {
+        self.transforms.push(transform);
+        self
+    }
+
+    /// The properties (besides `transform`) that are set by this step.
+    pub fn properties(&self) -> &[(Property, Value)] {
+        &self.properties
+    }
+
+    /// The transform functions of this step, in the order they are applied.
+    pub fn transforms(&self) -> &[Transform] {
+        &self.transforms
+    }
+
+    /// The step in between this step and the `other` step, by an `amount`
+    /// from 0 (this step) to 1 (the `other` step). Values that can't be
+    /// interpolated (e.g px and em) switch over halfway, as they do in CSS.
+    pub fn mix(&self, other: &KeyframeStep, amount: f64) -> KeyframeStep {
+        let halfway = amount >= 0.5;
+
+        let transforms = self
+            .transforms
+            .iter()
+            .zip(&other.transforms)
+            .map(|(from, to)| from.mix(to, amount))
+            .collect::<Option<Vec<_>>>()
+            .filter(|_| self.transforms.len() == other.transforms.len())
+            .unwrap_or_else(|| {
+                if halfway {
+                    other.transforms.clone()
+                } else {
+                    self.transforms.clone()
+                }
+            });
+
+        let mut properties = self
+            .properties
+            .iter()
+            .map(|(property, from)| {
+                let value = match other.properties.iter().find(|(other, _)| other == property) {
+                    Some((_, to)) => from
+                        .mix(to, amount)
+                        .unwrap_or_else(|| if halfway { to } else { from }.clone()),
+                    None => from.clone(),
+                };
+                (*property, value)
+            })
+            .collect::<Vec<_>>();
+        if halfway {
+            properties.extend(
+                other
+                    .properties
+                    .iter()
+                    .filter(|(property, _)| self.properties.iter().all(|(own, _)| own != property))
+                    .cloned(),
+            );
+        }
+
+        KeyframeStep {
+            properties,
+            transforms,
+        }
+    }
+
+    pub fn rotate(self, angle: Angle) -> Self {
+        self.transform(Transform::Rotate(angle))
+    }
+
+    pub fn rotate_x(self, angle: Angle) -> Self {
+        self.transform(Transform::RotateX(angle))
+    }
+
+    pub fn rotate_y(self, angle: Angle) -> Self {
+        self.transform(Transform::RotateY(angle))
+    }
+
+    pub fn scale(self, scale: f64) -> Self {
+        self.transform(Transform::Scale(scale))
+    }
+
+    pub fn translate_x(self, length: impl Into<Length>) -> Self {
+        self.transform(Transform::TranslateX(length.into()))
+    }
+
+    pub fn translate_y(self, length: impl Into<Length>) -> Self {
+        self.transform(Transform::TranslateY(length.into()))
+    }
+
+    pub fn perspective(self, length: impl Into<Length>) -> Self {
+        self.transform(Transform::Perspective(length.into()))
+    }
+
+    pub fn width(self, length: impl Into<Length>) -> Self {
+        self.set(Property::Width, Value::Length(length.into()))
+    }
+
+    pub fn height(self, length: impl Into<Length>) -> Self {
+        self.set(Property::Height, Value::Length(length.into()))
+    }
+
+    pub fn margin_top(self, length: impl Into<Length>) -> Self {
+        self.set(Property::MarginTop, Value::Length(length.into()))
+    }
+
+    pub fn margin_right(self, length: impl Into<Length>) -> Self {
+        self.set(Property::MarginRight, Value::Length(length.into()))
+    }
+
+    pub fn margin_bottom(self, length: impl Into<Length>) -> Self {
+        self.set(Property::MarginBottom, Value::Length(length.into()))
+    }
+
+    pub fn margin_left(self, length: impl Into<Length>) -> Self {
+        self.set(Property::MarginLeft, Value::Length(length.into()))
+    }
+
+    /// Sets the opacity, which has to be between 0.0 and 1.0.
+    pub fn opacity(self, opacity: f64) -> Self {
+        self.set(Property::Opacity, Value::Number(opacity))
+    }
+
+    pub fn border_radius(self, length: impl Into<Length>) -> Self {
+        self.set(Property::BorderRadius, Value::Length(length.into()))
+    }
+
+    /// Clips the element into a polygon, where each point is given as a
+    /// percentage of the element's (x, y).
+    pub fn clip_path(self, points: &[(f64, f64)]) -> Self {
+        self.set(Property::ClipPath, Value::Polygon(points.to_vec()))
+    }
+}
+
+/// A named set of keyframes, see the [module documentation](self).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyframes {
+    name: String,
+    steps: Vec<(Percent, KeyframeStep)>,
+}
+
+impl Keyframes {
+    pub fn new(name: impl Into<String>) -> Self {
+        Keyframes {
+            name: name.into(),
+            steps: Vec::new(),
+        }
+    }
+
+    /// Adds a step at the given offset. Steps have to be added in order.
+    pub fn at(mut self, offset: Percent, step: impl FnOnce(KeyframeStep) -> KeyframeStep) -> Self {
+        self.steps.push((offset, step(KeyframeStep::default())));
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The steps of the keyframes, in order.
+    pub fn steps(&self) -> &[(Percent, KeyframeStep)] {
+        &self.steps
+    }
+
+    /// The step at a `progress` from 0 to 1 through the keyframes, as the
+    /// browser would compute it with the given `easing` (e.g for drawing a
+    /// frame of the animation outside of CSS).
+    ///
+    /// ```
+    /// use spux::keyframes::{Easing, Keyframes, Transform, Unit};
+    ///
+    /// let keyframes = Keyframes::new("spux-spin")
+    ///     .at(0.pct(), |step| step.rotate(0.deg()))
+    ///     .at(100.pct(), |step| step.rotate(360.deg()));
+    ///
+    /// let step = keyframes.sample(0.25, Easing::Linear);
+    /// assert_eq!(step.transforms(), [Transform::Rotate(90.deg())]);
+    /// ```
+    pub fn sample(&self, progress: f64, easing: Easing) -> KeyframeStep {
+        let offset = progress.rem_euclid(1.0) * 100.0;
+        let segment = self.steps.windows(2).find(|steps| offset <= steps[1].0 .0);
+        match segment {
+            Some([(from_offset, from), (to_offset, to)]) => {
+                let amount = (offset - from_offset.0) / (to_offset.0 - from_offset.0);
+                from.mix(to, easing.apply(amount.clamp(0.0, 1.0)))
+            }
+            _ => self
+                .steps
+                .last()
+                .map(|(_, step)| step.clone())
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Checks that the keyframes have a valid name, that the steps are in
+    /// order and cover both 0% and 100%, and that every opacity is between
+    /// 0.0 and 1.0.
+    ///
+    /// ```
+    /// use spux::keyframes::{Keyframes, Unit};
+    ///
+    /// let unordered = Keyframes::new("spux-fade")
+    ///     .at(0.pct(), |step| step.opacity(1.0))
+    ///     .at(100.pct(), |step| step.opacity(1.0))
+    ///     .at(50.pct(), |step| step.opacity(0.2));
+    /// assert!(unordered.validate().is_err());
+    ///
+    /// let incomplete = Keyframes::new("spux-fade").at(50.pct(), |step| step.opacity(0.2));
+    /// assert!(incomplete.validate().is_err());
+    ///
+    /// let not_a_number = Keyframes::new("spux-fade")
+    ///     .at(0.pct(), |step| step.opacity(1.0))
+    ///     .at(f64::NAN.pct(), |step| step.opacity(0.2))
+    ///     .at(100.pct(), |step| step.opacity(1.0));
+    /// assert!(not_a_number.validate().is_err());
+    ///
+    /// let too_opaque = Keyframes::new("spux-fade")
+    ///     .at(0.pct(), |step| step.opacity(1.0))
+    ///     .at(100.pct(), |step| step.opacity(1.5));
+    /// assert!(too_opaque.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let valid_name = self
+            .name
+            .chars()
+            .next()
+            .is_some_and(|char| !char.is_ascii_digit())
+            && self
+                .name
+                .chars()
+                .all(|char| char.is_ascii_alphanumeric() || char == '-' || char == '_');
+        if !valid_name {
+            return Err(ValidationError::new(
+                "Keyframes should be named with letters, digits, - or _ (e.g spux-circle)",
+            ));
+        }
+
+        // a NaN offset compares as neither smaller nor larger than the other
+        // offsets, so it would otherwise slip through the order check below
+        if self.steps.iter().any(|(offset, _)| !offset.0.is_finite()) {
+            return Err(ValidationError::new(
+                "Keyframe offsets should be finite percentages (e.g 50%)",
+            ));
+        }
+
+        if self.steps.first().map(|(offset, _)| offset.0) != Some(0.0)
+            || self.steps.last().map(|(offset, _)| offset.0) != Some(100.0)
+        {
+            return Err(ValidationError::new(
+                "Keyframes should start at 0% and end at 100%",
+            ));
+        }
+
+        if self.steps.windows(2).any(|steps| steps[0].0 >= steps[1].0) {
+            return Err(ValidationError::new(
+                "Keyframe steps should be in order (e.g 0%, 50% and then 100%)",
+            ));
+        }
+
+        let valid_opacity = |value: &Value| matches!(value, Value::Number(opacity) if (0.0..=1.0).contains(opacity));
+        let invalid_opacity = self.steps.iter().any(|(_, step)| {
+            step.properties
+                .iter()
+                .any(|(property, value)| *property == Property::Opacity && !valid_opacity(value))
+        });
+        if invalid_opacity {
+            return Err(ValidationError::new(
+                "Keyframe opacity should be between 0 and 1",
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Validates the keyframes and serializes them into minified CSS.
+    pub fn to_css(&self) -> Result<String, ValidationError> {
+        self.validate()?;
+
+        let steps = self
+            .steps
+            .iter()
+            .map(|(offset, step)| {
+                let mut declarations = step
+                    .properties
+                    .iter()
+                    .map(|(property, value)| format!("{}:{value}", property.name()))
+                    .collect::<Vec<_>>();
+                if !step.transforms.is_empty() {
+                    let transforms = step
+                        .transforms
+                        .iter()
+                        .map(Transform::to_string)
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    declarations.push(format!("transform:{transforms}"));
+                }
+                // offsets are rounded to keep computed steps (e.g 33.33%) short
+                let offset = (offset.0 * 100.0).round() / 100.0;
+                format!("{offset}%{{{}}}", declarations.join(";"))
+            })
+            .collect::<String>();
+
+        Ok(format!("@keyframes {}{{{steps}}}", self.name))
+    }
+}
+
+/// The timing function of an animation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Easing {
+    Linear,
+    #[default]
+    EaseInOut,
+}
+
+impl Easing {
+    /// The name of the timing function as written in CSS.
+    pub fn css(&self) -> &'static str {
+        match self {
+            Easing::Linear => "linear",
+            Easing::EaseInOut => "ease-in-out",
+        }
+    }
+
+    /// Eases a `progress` from 0 to 1 through a step of the keyframes.
+    pub fn apply(&self, progress: f64) -> f64 {
+        match self {
+            Easing::Linear => progress,
+            // cubic-bezier(0.42, 0, 0.58, 1), solved for x by bisection
+            Easing::EaseInOut => {
+                let bezier = |t: f64, first: f64, second: f64| {
+                    3.0 * (1.0 - t).powi(2) * t * first
+                        + 3.0 * (1.0 - t) * t.powi(2) * second
+                        + t.powi(3)
+                };
+                let (mut low, mut high) = (0.0, 1.0);
+                for _ in 0..32 {
+                    let middle = (low + high) / 2.0;
+                    if bezier(middle, 0.42, 0.58) < progress {
+                        low = middle;
+                    } else {
+                        high = middle;
+                    }
+                }
+                bezier((low + high) / 2.0, 0.0, 1.0)
+            }
+        }
+    }
+}
+
+fn mix(from: f64, to: f64, amount: f64) -> f64 {
+    from + (to - from) * amount
+}
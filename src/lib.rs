@@ -5,126 +5,1133 @@
  * LICENSE file in the root directory of this source tree.
  */
 
-/*!
- Spux is a handcrafted and opinionated library of minimal loaders for Leptos.
-
- Getting started
- ----
- First install Leptos from <https://leptos.dev>
-
- Then start a new Leptos project with either of the following commands:
+//!  Spux is a handcrafted and opinionated library of minimal loaders for Leptos.
+//!
+//!  Getting started
+//!  ----
+//!  First install Leptos from <https://leptos.dev>
+//!
+//!  Then start a new Leptos project with either of the following commands:
+//!
+//!
+//!  Axum: `cargo leptos new --git https://github.com/leptos-rs/start-axum`
+//!
+//!  Actix Web: `cargo leptos new --git https://github.com/leptos-rs/start-actix`
+//!
+//!  Lastly install and add Spux at the root of the new project
+//! ```text
+//! cargo add spux
+//! ```
+//!
+//! # Usage
+//!
+//! Spux loaders are separated into `spinners` and `pulsers`.
+//!
+//! v0.1.1 comes packed with:
+//!
+//! `spinners`::
+//! * `Square`
+//! * `Triangle`
+//! * `FilledSquare`
+//! * `PartialCircle`
+//!
+//! `pulsers`::
+//! * `Circle`
+//! * `Diamond`
+//! * `FilledCircle`
+//! * `FilledDiamond`
+//!
+//! To use the various `spinners` and `pulsers`, enable them via features in your `Cargo.toml`:
+//! ```toml
+//! [dependencies]
+//! spux = { version = "0.1.1", features = ["spinners", "pulsers"] }
+//! ```
+//!
+//!
+//! Once Spux is installed, include the pulser or spinner that you want to use
+#![cfg_attr(all(feature = "leptos", feature = "pulsers"), doc = "```rust")]
+#![cfg_attr(not(all(feature = "leptos", feature = "pulsers")), doc = "```ignore")]
+//! use leptos::prelude::*;
+//! use spux::pulsers::Circle;
+//!
+//! #[component]
+//! fn App() -> impl IntoView {
+//!
+//!     view! {
+//!         <Circle color="#000000" size=10 />
+//!     }
+//! }
+//! ```
+//!
+//!
+//! Each loader takes in a required `size` (by px) and an optional `color` (#hex), which defaults
+//! to the color of the surrounding text (the text loaders only take the `color`). The skeletons
+//! take a required `color`, which their shades are mixed from.
+//!
+//! | Prop    | Type | Example   |
+//! | :------ | :--- | :-------- |
+//! | color   | &str | "#000000" |
+//! | size    | u32 or `Size` | 15 or `Size::Em(1.0)` |
+//!
+//!
+#![cfg_attr(all(feature = "leptos", feature = "pulsers"), doc = "```rust")]
+#![cfg_attr(not(all(feature = "leptos", feature = "pulsers")), doc = "```ignore")]
+//! use leptos::prelude::*;
+//! use spux::pulsers::Diamond;
+//!
+//! #[component]
+//! fn App() -> impl IntoView {
+//!
+//!     view! {
+//!         <Diamond color="#000000" size=10 />
+//!     }
+//! }
+//! ```
+//!
+//! Spux components can also be used with `Suspense` in Leptos.
+#![cfg_attr(all(feature = "leptos", feature = "spinners"), doc = "```rust")]
+#![cfg_attr(not(all(feature = "leptos", feature = "spinners")), doc = "```ignore")]
+//! use leptos::prelude::*;
+//! use spux::spinners::FilledSquare;
+//!
+//! #[component]
+//! fn App() -> impl IntoView {
+//!
+//!   // posts_view consists of a server function that's being called to
+//!   // return a list of posts. see examples/basic-spinner on the GitHub page
+//!   // for more details
+//!   # let posts_view = move || Suspend::new(async { "" });
+//!   view! {
+//!     <Suspense fallback=move || view! {
+//!         <div
+//!             style:width="full"
+//!             style:margin-x="auto"
+//!             style:align-items="center"
+//!             style:justify-content="center"
+//!             style:display="flex"
+//!         >
+//!             <FilledSquare color="#000000" size=10 />
+//!         </div>
+//!     }>
+//!         <div>
+//!             <p>"Posts"</p>
+//!             <hr />
+//!             {posts_view}
+//!         </div>
+//!     </Suspense>
+//!
+//!   }
+//! }
+//! ```
+//!
+//! # Progress
+//!
+//! When the percentage of work done is known, enable the `progress` feature and use
+//! `LinearProgress` or `CircularProgress`. Both take an optional `value` (0.0 to 1.0)
+//! that animates smoothly as it changes, and an optional `buffer`. Without a `value`
+//! they fall back to an indeterminate animation. The optional `color` defaults to the
+//! color of the surrounding text and the `label` announced to assistive technologies
+//! defaults to "Loading".
+#![cfg_attr(all(feature = "leptos", feature = "progress"), doc = "```rust")]
+#![cfg_attr(not(all(feature = "leptos", feature = "progress")), doc = "```ignore")]
+//! use leptos::prelude::*;
+//! use spux::progress::{CircularProgress, LinearProgress};
+//!
+//! #[component]
+//! fn App() -> impl IntoView {
+//!     let value = RwSignal::new(0.4);
+//!
+//!     view! {
+//!         <LinearProgress color="#000000" size=4 value=value buffer=0.6 />
+//!         <CircularProgress size=24 value=value label="Uploading" />
+//!     }
+//! }
+//! ```
+//!
+//! # Skeletons
+//!
+//! For content shaped loading states, enable the `skeletons` feature. `SkeletonText`,
+//! `SkeletonAvatar`, `SkeletonRect`, `SkeletonCard` and `SkeletonTable` take a base
+//! `color` and an optional `highlight` (a lighter shade of `color` by default), and
+//! animate with either a shared-phase `SkeletonAnimation::Shimmer` or a `SkeletonAnimation::Pulse`.
+#![cfg_attr(all(feature = "leptos", feature = "skeletons"), doc = "```rust")]
+#![cfg_attr(not(all(feature = "leptos", feature = "skeletons")), doc = "```ignore")]
+//! use leptos::prelude::*;
+//! use spux::skeletons::{SkeletonAnimation, SkeletonTable, SkeletonText};
+//!
+//! #[component]
+//! fn App() -> impl IntoView {
+//!     view! {
+//!         <SkeletonText color="#e0e0e0" size=12 lines=3 last_line_width=60 />
+//!         <SkeletonTable color="#e0e0e0" size=16 rows=5 cols=4 animation=SkeletonAnimation::Pulse />
+//!     }
+//! }
+//! ```
+//!
+//! # Text
+//!
+//! Where a shape loader looks out of place, such as inline in a sentence, enable the
+//! `text` feature. `Ellipsis`, `ShimmerText`, `Typewriter` and `Scramble` animate a
+//! piece of `text` (defaults to "Loading"), inherit the font of the surrounding text
+//! and take the usual `color` along with an optional `duration` (by ms).
+#![cfg_attr(all(feature = "leptos", feature = "text"), doc = "```rust")]
+#![cfg_attr(not(all(feature = "leptos", feature = "text")), doc = "```ignore")]
+//! use leptos::prelude::*;
+//! use spux::text::{Ellipsis, Typewriter};
+//!
+//! #[component]
+//! fn App() -> impl IntoView {
+//!     view! {
+//!         <p>"Hang tight, " <Ellipsis color="#000000" text="we are fetching your posts" /></p>
+//!         <p><Typewriter color="#000000" text="Thinking" duration=2000 /></p>
+//!     }
+//! }
+//! ```
+//!
+//! # Morphers
+//!
+//! Enable the `morphers` feature to use `Morph`, a filled shape that morphs through a
+//! sequence of `spux::shapes::Shape`s in a loop. `shapes` defaults to a square, a circle
+//! and a diamond, and `duration` (by ms) is the time spent on each shape.
+#![cfg_attr(all(feature = "leptos", feature = "morphers"), doc = "```rust")]
+#![cfg_attr(not(all(feature = "leptos", feature = "morphers")), doc = "```ignore")]
+//! use leptos::prelude::*;
+//! use spux::morphers::Morph;
+//! use spux::shapes::Shape;
+//!
+//! #[component]
+//! fn App() -> impl IntoView {
+//!     view! {
+//!         <Morph color="#000000" size=16 shapes=[Shape::Square, Shape::Circle, Shape::Diamond] />
+//!     }
+//! }
+//! ```
+//!
+//! # Grids
+//!
+//! Enable the `grids` feature to render a `Grid` of `rows` by `cols` cells that animate
+//! one after the other. The `pattern` (`Stagger::Diagonal`, `Stagger::Spiral`,
+//! `Stagger::Seeded(seed)` or `Stagger::Rows`) decides the order in which the cells start,
+//! and the `effect` (`GridEffect::Scale`, `GridEffect::Fade`, `GridEffect::Rotate` or
+//! `GridEffect::Fold`) decides how each cell is animated. `CubeGrid` and `FoldingCube`
+//! come preconfigured.
+#![cfg_attr(all(feature = "leptos", feature = "grids"), doc = "```rust")]
+#![cfg_attr(not(all(feature = "leptos", feature = "grids")), doc = "```ignore")]
+//! use leptos::prelude::*;
+//! use spux::grids::{CubeGrid, Grid, GridEffect, Stagger};
+//!
+//! #[component]
+//! fn App() -> impl IntoView {
+//!     view! {
+//!         <CubeGrid color="#000000" size=18 />
+//!         <Grid color="#000000" size=6 rows=4 cols=4 gap=2
+//!             pattern=Stagger::Seeded(7)
+//!             effect=GridEffect::Fade
+//!             label="Loading results"
+//!         />
+//!     }
+//! }
+//! ```
+//!
+//! # 3D
+//!
+//! For something more eye-catching than a flat rotation, enable the `three_d` feature.
+//! `FlipSquare` flips around its X and Y axes in turn (outlined, or `filled=true`),
+//! `Cube` rotates a cube whose `faces` can each be given their own color, and
+//! `CardFlip` keeps flipping a card over.
+#![cfg_attr(all(feature = "leptos", feature = "three_d"), doc = "```rust")]
+#![cfg_attr(not(all(feature = "leptos", feature = "three_d")), doc = "```ignore")]
+//! use leptos::prelude::*;
+//! use spux::three_d::{CardFlip, Cube, FlipSquare};
+//!
+//! #[component]
+//! fn App() -> impl IntoView {
+//!     view! {
+//!         <FlipSquare color="#000000" size=16 />
+//!         <Cube color="#000000" size=16 />
+//!         <CardFlip color="#000000" size=16 />
+//!     }
+//! }
+//! ```
+//!
+//! # Overlays
+//!
+//! For long or destructive operations, enable the `overlay` feature and wrap any loader
+//! in a `SpuxOverlay`. While `show` is true it covers the page with a translucent backdrop
+//! and a centered loader (and an optional `message`), blocks pointer events, traps the
+//! focus and locks the scrolling of the page. The focus is restored once it is hidden.
+#![cfg_attr(all(feature = "overlay", feature = "pulsers"), doc = "```rust")]
+#![cfg_attr(not(all(feature = "overlay", feature = "pulsers")), doc = "```ignore")]
+//! use leptos::prelude::*;
+//! use spux::overlay::SpuxOverlay;
+//! use spux::pulsers::Circle;
+//!
+//! #[component]
+//! fn App() -> impl IntoView {
+//!     let deleting = RwSignal::new(false);
+//!
+//!     view! {
+//!         <SpuxOverlay show=deleting message="Deleting your account" backdrop="#000000" opacity=0.4>
+//!             <Circle color="#ffffff" size=24 />
+//!         </SpuxOverlay>
+//!     }
+//! }
+//! ```
+//!
+//! To cover a single element instead (e.g a card, a table or a chart while it refetches),
+//! use a `LoadingOverlay` with a `target` `NodeRef`. It follows the bounding box of the
+//! target as it is resized or scrolled, marks it with `aria-busy` and leaves the rest of
+//! the page interactive.
+#![cfg_attr(all(feature = "overlay", feature = "spinners"), doc = "```rust")]
+#![cfg_attr(not(all(feature = "overlay", feature = "spinners")), doc = "```ignore")]
+//! use leptos::html::Div;
+//! use leptos::prelude::*;
+//! use spux::overlay::LoadingOverlay;
+//! use spux::spinners::PartialCircle;
+//!
+//! #[component]
+//! fn App() -> impl IntoView {
+//!     let chart = NodeRef::<Div>::new();
+//!     let refetching = RwSignal::new(true);
+//!
+//!     view! {
+//!         <div node_ref=chart>"Chart"</div>
+//!         <LoadingOverlay target=chart show=refetching>
+//!             <PartialCircle color="#000000" size=16 />
+//!         </LoadingOverlay>
+//!     }
+//! }
+//! ```
+//!
+//! # Inline loaders
+//!
+//! To place a loader inside of a sentence or a button, give it a `size` of `Size::Em`.
+//! The loader is then rendered inline, sized relative to the font of the surrounding text
+//! and aligned with it. Leave out the `color` to use the color of the surrounding text.
+#![cfg_attr(all(feature = "leptos", feature = "pulsers"), doc = "```rust")]
+#![cfg_attr(not(all(feature = "leptos", feature = "pulsers")), doc = "```ignore")]
+//! use leptos::prelude::*;
+//! use spux::pulsers::Circle;
+//! use spux::size::Size;
+//!
+//! #[component]
+//! fn App() -> impl IntoView {
+//!     view! {
+//!         <button>"Saving " <Circle size=Size::Em(1.0) /></button>
+//!     }
+//! }
+//! ```
+//!
+//! # Composing loaders
+//!
+//! Enable the `compose` feature to stack several loaders on a shared center. Each loader
+//! is wrapped in a `Layer`, which can `scale` it and offset its animation by a `delay`
+//! (by ms, a negative delay shifts its phase without waiting). The combined loader is
+//! announced once, by its `label` (defaults to "Loading").
+#![cfg_attr(
+    all(feature = "compose", feature = "pulsers", feature = "spinners"),
+    doc = "```rust"
+)]
+#![cfg_attr(
+    not(all(feature = "compose", feature = "pulsers", feature = "spinners")),
+    doc = "```ignore"
+)]
+//! use leptos::prelude::*;
+//! use spux::compose::{Compose, Layer};
+//! use spux::pulsers::FilledCircle;
+//! use spux::spinners::PartialCircle;
+//!
+//! #[component]
+//! fn App() -> impl IntoView {
+//!     view! {
+//!         <Compose size=32>
+//!             <Layer>
+//!                 <PartialCircle color="#000000" size=28 />
+//!             </Layer>
+//!             <Layer scale=0.4 delay=-700>
+//!                 <FilledCircle color="#000000" size=28 />
+//!             </Layer>
+//!         </Compose>
+//!     }
+//! }
+//! ```
+//!
+//! # Custom loaders
+//!
+//! Brand specific loaders can be declared with the `spux_loader!` macro, which generates a
+//! Leptos component with the same props, validation, accessibility and theming as the
+//! loaders that come with Spux. The `style` and `keyframes` are plain CSS that can use the
+//! `--spux-color`, `--spux-size`, `--spux-duration` and `--spux-index` custom properties.
+#![cfg_attr(feature = "leptos", doc = "```rust")]
+#![cfg_attr(not(feature = "leptos"), doc = "```ignore")]
+//! use leptos::prelude::*;
+//! use spux::spux_loader;
+//!
+//! spux_loader! {
+//!     /// Renders three dots that fade in and out one after the other.
+//!     pub BrandDots {
+//!         class: "brand-dots",
+//!         elements: 3,
+//!         style: "
+//!             .brand-dots {
+//!                 width: calc(var(--spux-size) / 4);
+//!                 height: calc(var(--spux-size) / 4);
+//!                 margin: auto;
+//!                 border-radius: 100%;
+//!                 background-color: var(--spux-color);
+//!                 animation: brand-dots var(--spux-duration) ease-in-out infinite;
+//!                 animation-delay: calc(var(--spux-index) * var(--spux-duration) / 6);
+//!             }
+//!         ",
+//!         keyframes: "
+//!             @keyframes brand-dots {
+//!                 0%, 100% { opacity: 0.2; }
+//!                 50% { opacity: 1; }
+//!             }
+//!         ",
+//!     }
+//! }
+//! ```
+//!
+//! # Typed keyframes
+//!
+//! Keyframes can be built with the typed `Keyframes` builder instead of raw CSS, so typos
+//! in property names and units don't compile, and steps that are out of order or don't
+//! cover 0% and 100% are reported rather than silently not animating. The keyframes are
+//! serialized into minified CSS and can be given to `spux_loader!` directly.
+//! ```
+//! use spux::keyframes::{Keyframes, Length, Unit};
+//!
+//! let half = Length::spux_size().divided(2.0);
+//! let keyframes = Keyframes::new("brand-spin")
+//!     .at(0.pct(), |step| step.rotate(45.deg()))
+//!     .at(50.pct(), |step| step.rotate(270.deg()).margin_right(half))
+//!     .at(100.pct(), |step| step.rotate(405.deg()));
+//!
+//! let css = keyframes.to_css().expect("valid keyframes");
+//! ```
+//!
+//! # SVG rendering
+//!
+//! The spinners and pulsers can render as inline SVG instead of styled elements, which keeps
+//! them crisp at any size and gives them rounded strokes (the `--spux-linecap` custom
+//! property overrides the `stroke-linecap`). Pass `renderer=Renderer::Svg`, or enable the
+//! `svg` feature to make SVG the default renderer. The other loaders have no SVG drawing and
+//! always render as elements. The same SVG is also available as a string or a data URI for an
+//! `<img>` or a CSS `background-image`.
+#![cfg_attr(all(feature = "leptos", feature = "spinners"), doc = "```rust")]
+#![cfg_attr(not(all(feature = "leptos", feature = "spinners")), doc = "```ignore")]
+//! use leptos::prelude::*;
+//! use spux::spinners::{partial_circle_svg, PartialCircle};
+//! use spux::svg::Renderer;
+//!
+//! #[component]
+//! fn App() -> impl IntoView {
+//!     let background = partial_circle_svg().data_uri("#000000", 32, 1400).unwrap();
+//!
+//!     view! {
+//!         <PartialCircle color="#000000" size=32 renderer=Renderer::Svg />
+//!         <div style=format!("background-image: url(\"{background}\")")></div>
+//!     }
+//! }
+//! ```
+//!
+//! # Canvas
+//!
+//! For pages with thousands of loaders (e.g a dashboard with a loader in every cell), the
+//! `canvas` feature adds `SpuxCanvas`, which draws every loader into a single `<canvas>` from
+//! one `requestAnimationFrame` loop. Loaders are drawn from the same SVG drawings, either at
+//! a position on the canvas or centered on every element that matches a selector.
+#![cfg_attr(all(feature = "canvas", feature = "spinners"), doc = "```rust")]
+#![cfg_attr(not(all(feature = "canvas", feature = "spinners")), doc = "```ignore")]
+//! use leptos::prelude::*;
+//! use spux::canvas::{CanvasLoader, SpuxCanvas};
+//! use spux::spinners::partial_circle_svg;
+//!
+//! #[component]
+//! fn App() -> impl IntoView {
+//!     let loaders = vec![CanvasLoader::anchored(partial_circle_svg(), ".cell-loading")];
+//!
+//!     view! {
+//!         <SpuxCanvas loaders=loaders />
+//!     }
+//! }
+//! ```
+//!
+//! # Web Animations
+//!
+//! The `web_animations` feature drives loaders through the Web Animations API instead of
+//! `@keyframes`, so no `<style>` is injected into the page. A `LoaderAnimation` handle
+//! pauses, seeks and changes the playback rate of the animation from Rust, and its
+//! `finished` future resolves once a loader with a limited number of iterations is done.
+#![cfg_attr(all(feature = "spinners", feature = "web_animations"), doc = "```rust")]
+#![cfg_attr(
+    not(all(feature = "spinners", feature = "web_animations")),
+    doc = "```ignore"
+)]
+//! use leptos::prelude::*;
+//! use spux::spinners::PartialCircle;
+//! use spux::svg::Renderer;
+//! use spux::web_animations::LoaderAnimation;
+//!
+//! #[component]
+//! fn App() -> impl IntoView {
+//!     let animation = LoaderAnimation::new();
+//!
+//!     view! {
+//!         <PartialCircle color="#000000" size=16 renderer=Renderer::WebAnimations(animation) />
+//!         <button on:click=move |_| animation.pause()>"Pause"</button>
+//!     }
+//! }
+//! ```
+//!
+//! # Exporting loaders
+//!
+//! Pages that aren't built with Leptos (e.g a static marketing site) can use the same loaders
+//! through the `spux` binary, which is installed with the `cli` feature. It exports a loader as
+//! standalone HTML (with its `<style>`), as a stylesheet for that markup, or as an SVG document,
+//! all rendered from the same definitions as the Leptos components.
+//! ```text
+//! cargo install spux --features cli
+//! spux list
+//! spux export circle --color '#112233' --size 24 --format css|html|svg
+//! ```
+//! The definitions are also available from Rust, e.g `spux::pulsers::circle_definition()`.
+//!
+//! # Animated images
+//!
+//! The `raster` feature renders loaders into animated GIF, APNG or (lossless) WebP images
+//! (e.g for emails, READMEs or chat bots) in plain Rust, without a browser or a GPU, so the
+//! images can be generated from a build script. One cycle of the animation is sampled at the
+//! given frame rate and drawn in software.
+#![cfg_attr(all(feature = "pulsers", feature = "raster"), doc = "```no_run")]
+#![cfg_attr(not(all(feature = "pulsers", feature = "raster")), doc = "```ignore")]
+//! use spux::pulsers::circle_svg;
+//! use spux::raster::{ImageFormat, Rasterizer};
+//!
+//! let gif = Rasterizer::new(circle_svg())
+//!     .color("#112233")
+//!     .size(64)
+//!     .fps(30)
+//!     .encode(ImageFormat::Gif)
+//!     .unwrap();
+//! std::fs::write("circle.gif", gif).unwrap();
+//! ```
+//! The `spux` binary exports the same images, e.g
+//! `spux export circle --size 64 --format gif --fps 30 -o circle.gif` (or `--format apng` and
+//! `--format webp`).
+//!
+//! # Rendering without a framework
+//!
+//! The `core` module renders any loader into its markup and CSS without Leptos (or any other
+//! framework), e.g for server-side templates. It is what the Leptos components are rendered
+//! from, so a loader looks the same everywhere.
+#![cfg_attr(feature = "pulsers", doc = "```rust")]
+#![cfg_attr(not(feature = "pulsers"), doc = "```ignore")]
+//! use spux::core::{render, LoaderConfig};
+//! use spux::pulsers::circle_definition;
+//!
+//! let rendered = render(LoaderConfig::new(circle_definition(), 24).color("#112233")).unwrap();
+//! // rendered.html, rendered.css and rendered.class_names
+//! ```
+//! The other families come with their own configs (e.g `LinearProgressConfig`,
+//! `SkeletonTextConfig`, `TypewriterConfig`, `GridConfig`, `MorphConfig` or `CubeConfig`),
+//! which are rendered the same way and don't need Leptos either.
+//!
+//! # Dioxus
+//!
+//! The `dioxus` feature exposes the `spinners` and `pulsers` as Dioxus components under
+//! `spux::dioxus`, with the same props as the Leptos components and rendered from the same
+//! definitions. Leptos is a default feature, so it can be left out for Dioxus only projects:
+//! ```toml
+//! [dependencies]
+//! spux = { version = "0.1.1", default-features = false, features = ["dioxus", "pulsers"] }
+//! ```
+#![cfg_attr(all(feature = "dioxus", feature = "pulsers"), doc = "```rust")]
+#![cfg_attr(not(all(feature = "dioxus", feature = "pulsers")), doc = "```ignore")]
+//! use dioxus::prelude::*;
+//! use spux::dioxus::pulsers::Circle;
+//!
+//! fn App() -> Element {
+//!     rsx! {
+//!         Circle { color: "#000000", size: 10u32 }
+//!     }
+//! }
+//! ```
+//! Custom loaders declared with `spux_loader!` (and named after a function) are rendered with
+//! `spux::dioxus::SpuxLoader { definition: brand_dots(), size: 24u32 }`.
+//!
+//! # Yew and Sycamore
+//!
+//! The `yew` and `sycamore` features expose every family of loaders (the `spinners`, `pulsers`,
+//! `progress`, `skeletons`, `text`, `grids`, `morphers` and `three_d` components) as native
+//! components of each framework, under `spux::yew` and `spux::sycamore`. They take the same props
+//! as the Leptos components and are rendered from the same definitions and configs (see
+//! `spux::core`), and custom loaders are rendered by the `SpuxLoader` component of each module.
+#![cfg_attr(
+    all(feature = "yew", feature = "pulsers", feature = "progress"),
+    doc = "```rust"
+)]
+#![cfg_attr(
+    not(all(feature = "yew", feature = "pulsers", feature = "progress")),
+    doc = "```ignore"
+)]
+//! use spux::yew::progress::LinearProgress;
+//! use spux::yew::pulsers::Circle;
+//! use yew::prelude::*;
+//!
+//! #[function_component]
+//! fn App() -> Html {
+//!     html! {
+//!         <>
+//!             <Circle color="#000000" size={10} />
+//!             <LinearProgress color="#000000" size={4} value={0.25} />
+//!         </>
+//!     }
+//! }
+//! ```
+#![cfg_attr(
+    all(feature = "sycamore", feature = "pulsers", feature = "progress"),
+    doc = "```rust"
+)]
+#![cfg_attr(
+    not(all(feature = "sycamore", feature = "pulsers", feature = "progress")),
+    doc = "```ignore"
+)]
+//! use spux::sycamore::progress::LinearProgress;
+//! use spux::sycamore::pulsers::Circle;
+//! use sycamore::prelude::*;
+//!
+//! #[component]
+//! fn App() -> View {
+//!     view! {
+//!         Circle(color="#000000", size=10)
+//!         LinearProgress(color="#000000", size=4, value=Some(0.25))
+//!     }
+//! }
+//! ```
+//!
+//! # Terminal
+//!
+//! The `terminal` feature brings the loaders to CLI tools and TUIs. Every built-in loader has a
+//! sequence of Unicode glyphs (e.g `◰◳◲◱` for `Square` and `·•●•` for `Circle`), which a
+//! `TerminalLoader` cycles through over its `duration`, colored from a #hex color. It can spin
+//! on stdout from a background thread, or be drawn as a `ratatui` `StatefulWidget`.
+#![cfg_attr(all(feature = "pulsers", feature = "terminal"), doc = "```no_run")]
+#![cfg_attr(not(all(feature = "pulsers", feature = "terminal")), doc = "```ignore")]
+//! use spux::pulsers::circle_definition;
+//! use spux::terminal::TerminalLoader;
+//!
+//! let ticker = TerminalLoader::new(circle_definition())
+//!     .color("#112233")?
+//!     .duration(800)
+//!     .label("Downloading")
+//!     .spawn();
+//! // ...
+//! ticker.stop();
+//! # Ok::<(), spux::errors::ValidationError>(())
+//! ```
+//! Custom loaders declared with `spux_loader!` can set their own glyphs with `glyphs: &["·", "•", "●", "•"]`.
+//!
+//! # Web Components
+//!
+//! The `web-components` feature registers every loader as a custom element (e.g `<spux-circle>`),
+//! so React or plain HTML apps can use exactly the same loaders as Leptos apps. The attributes of
+//! the elements are the props of the components, and are observed, so changing them re-renders
+//! the loader. Each loader is rendered into the shadow DOM of its element, so its styles are
+//! encapsulated. The `examples/web-components` crate builds the drop-in JS/WASM bundle.
+//! ```html
+//! <spux-circle color="#000000" size="12"></spux-circle>
+//!
+//! <script type="module">
+//!   import init, { defineSpuxElements } from "./pkg/web_components.js";
+//!
+//!   await init();
+//!   defineSpuxElements();
+//! </script>
+//! ```
+//!
+//! # Stylesheets in the head
+//!
+//! By default every loader renders its `<style>` right before itself. With the `head_styles` feature
+//! and `provide_spux_styles()` (next to `leptos_meta`'s `provide_meta_context()`), the stylesheets
+//! of all the loaders of a render are gathered into one `<style id="spux">` in the `<head>`, with
+//! each stylesheet included once. The sheet rendered on the server is hydrated rather than
+//! injected again.
+#![cfg_attr(feature = "head_styles", doc = "```rust")]
+#![cfg_attr(not(feature = "head_styles"), doc = "```ignore")]
+//! use leptos::prelude::*;
+//! use leptos_meta::provide_meta_context;
+//! use spux::head_styles::provide_spux_styles;
+//!
+//! #[component]
+//! fn App() -> impl IntoView {
+//!     provide_meta_context();
+//!     provide_spux_styles();
+//!     // ...
+//! }
+//! ```
+//! Every component goes through the same sheet, so a page of loaders (rendered here on the
+//! server) carries a single `<style>`, with each stylesheet included once:
+#![cfg_attr(
+    all(
+        feature = "head_styles",
+        feature = "ssr",
+        feature = "compose",
+        feature = "grids",
+        feature = "morphers",
+        feature = "progress",
+        feature = "pulsers",
+        feature = "skeletons",
+        feature = "text",
+        feature = "three_d"
+    ),
+    doc = "```rust"
+)]
+#![cfg_attr(
+    not(all(
+        feature = "head_styles",
+        feature = "ssr",
+        feature = "compose",
+        feature = "grids",
+        feature = "morphers",
+        feature = "progress",
+        feature = "pulsers",
+        feature = "skeletons",
+        feature = "text",
+        feature = "three_d"
+    )),
+    doc = "```ignore"
+)]
+//! use futures::StreamExt;
+//! use leptos::prelude::*;
+//! use leptos_meta::{provide_meta_context, MetaTags, ServerMetaContext};
+//! use spux::compose::{Compose, Layer};
+//! use spux::grids::CubeGrid;
+//! use spux::head_styles::provide_spux_styles;
+//! use spux::morphers::Morph;
+//! use spux::progress::LinearProgress;
+//! use spux::pulsers::FilledCircle;
+//! use spux::skeletons::SkeletonCard;
+//! use spux::text::Ellipsis;
+//! use spux::three_d::Cube;
+//!
+//! #[component]
+//! fn Page() -> impl IntoView {
+//!     provide_meta_context();
+//!     provide_spux_styles();
+//!
+//!     view! {
+//!         <LinearProgress color="#000000" size=4 value=0.25 />
+//!         <LinearProgress color="#000000" size=4 value=0.75 />
+//!         <SkeletonCard color="#e0e0e0" size=12 />
+//!         <SkeletonCard color="#e0e0e0" size=16 />
+//!         <Ellipsis text="Saving" />
+//!         <Ellipsis text="Loading" />
+//!         <CubeGrid size=6 />
+//!         <CubeGrid size=8 />
+//!         <Morph size=16 />
+//!         <Morph size=24 />
+//!         <Cube size=16 />
+//!         <Cube size=24 />
+//!         <Compose size=24><Layer><FilledCircle size=24 /></Layer></Compose>
+//!         <Compose size=32><Layer><FilledCircle size=32 /></Layer></Compose>
+//!     }
+//! }
+//!
+//! let owner = Owner::new();
+//! owner.set();
+//! let (meta, output) = ServerMetaContext::new();
+//! provide_context(meta);
+//!
+//! let page = view! { <html><head><MetaTags /></head><body><Page /></body></html> };
+//! let html = futures::executor::block_on(async {
+//!     let stream = output.inject_meta_context(page.to_html_stream_in_order()).await;
+//!     stream.collect::<String>().await
+//! });
+//!
+//! assert_eq!(html.matches("<style").count(), 1);
+//! assert_eq!(html.matches(".spux-compose {").count(), 1);
+//! for keyframes in [
+//!     "spux-linear-progress",
+//!     "spux-skeleton-shimmer",
+//!     "spux-ellipsis-1",
+//!     "spux-grid-scale",
+//!     "spux-morph-square-circle-diamond",
+//!     "spux-cube",
+//! ] {
+//!     assert_eq!(html.matches(&format!("@keyframes {keyframes}")).count(), 1);
+//! }
+//! ```
+//! The `<head>` is sent along with the first chunk of the page, so with streaming (e.g
+//! `SsrMode::OutOfOrder`) the sheet covers the loaders rendered up front and the `Suspense`
+//! fallbacks. The content of a `Suspense` is streamed once it resolves, and its loaders reuse the
+//! sheet, only rendering their own `<style>` for a stylesheet that isn't in it yet:
+#![cfg_attr(
+    all(
+        feature = "head_styles",
+        feature = "ssr",
+        feature = "morphers",
+        feature = "progress",
+        feature = "text"
+    ),
+    doc = "```rust"
+)]
+#![cfg_attr(
+    not(all(
+        feature = "head_styles",
+        feature = "ssr",
+        feature = "morphers",
+        feature = "progress",
+        feature = "text"
+    )),
+    doc = "```ignore"
+)]
+//! use futures::channel::oneshot;
+//! use futures::StreamExt;
+//! use leptos::prelude::*;
+//! use leptos_meta::{provide_meta_context, MetaTags, ServerMetaContext};
+//! use spux::head_styles::provide_spux_styles;
+//! use spux::morphers::Morph;
+//! use spux::progress::LinearProgress;
+//! use spux::text::Ellipsis;
+//!
+//! #[component]
+//! fn Page(loaded: oneshot::Receiver<()>) -> impl IntoView {
+//!     provide_meta_context();
+//!     provide_spux_styles();
+//!
+//!     view! {
+//!         <Ellipsis text="Loading" />
+//!         <Suspense fallback=|| view! { <Morph size=16 /> }>
+//!             {Suspend::new(async move {
+//!                 let _ = loaded.await;
+//!                 view! { <Ellipsis text="Saving" /><LinearProgress size=4 /> }
+//!             })}
+//!         </Suspense>
+//!     }
+//! }
+//!
+//! # any_spawner::Executor::init_futures_executor().unwrap();
+//! let owner = Owner::new();
+//! owner.set();
+//! let (meta, output) = ServerMetaContext::new();
+//! provide_context(meta);
+//!
+//! // the content of the `Suspense` only resolves once the first chunk is sent
+//! let (load, loaded) = oneshot::channel();
+//! let page = view! { <html><head><MetaTags /></head><body><Page loaded /></body></html> };
+//! let html = futures::executor::block_on(async {
+//!     let stream = output.inject_meta_context(page.to_html_stream_out_of_order()).await;
+//!     let mut stream = Box::pin(stream);
+//!     let shell = stream.next().await.unwrap();
+//!     load.send(()).unwrap();
+//!     shell + &stream.collect::<String>().await
+//! });
+//! let (head, body) = html.split_once("</head>").unwrap();
+//! let (_, resolved) = body.split_once("<template").unwrap();
+//!
+//! assert_eq!(head.matches("<style").count(), 1);
+//! assert!(head.contains("@keyframes spux-ellipsis-1"));
+//! assert!(head.contains("@keyframes spux-morph-square-circle-diamond"));
+//! assert!(resolved.contains("Saving"));
+//! assert!(!resolved.contains("@keyframes spux-ellipsis-1"));
+//! assert_eq!(resolved.matches("<style").count(), 1);
+//! assert!(resolved.contains("@keyframes spux-linear-progress"));
+//! ```
+//! While hydrating, loaders render the same markup as on the server (with no `<style>` for the
+//! stylesheets in the sheet), and the `<style id="spux">` of the server is hydrated:
+#![cfg_attr(
+    all(feature = "head_styles", feature = "hydrate", feature = "pulsers"),
+    doc = "```no_run"
+)]
+#![cfg_attr(
+    not(all(feature = "head_styles", feature = "hydrate", feature = "pulsers")),
+    doc = "```ignore"
+)]
+//! use leptos::prelude::*;
+//! use leptos_meta::provide_meta_context;
+//! use spux::head_styles::provide_spux_styles;
+//! use spux::pulsers::Circle;
+//!
+//! #[component]
+//! fn App() -> impl IntoView {
+//!     provide_meta_context();
+//!     provide_spux_styles();
+//!
+//!     view! {
+//!         <Circle size=12 />
+//!         <Circle size=24 />
+//!     }
+//! }
+//!
+//! leptos::mount::hydrate_body(App);
+//! ```
+//!
+//! # Content-Security-Policy
+//!
+//! Loaders render their stylesheets into `<style>` elements, which a strict `style-src` blocks.
+//! With the `nonce` feature, every `<style>` rendered by Spux carries the nonce of the render
+//! from Leptos' `use_nonce()` (after `leptos::nonce::provide_nonce()`), so the policy only has to
+//! allow `'nonce-…'`.
+//!
+//! Alternatively, the stylesheet of every component can be served as a file, generated with
+//! `spux stylesheet -o spux.css` (or `spux::csp::built_in_stylesheet()` from a build script).
+//! Below `provide_external_stylesheet()`, components render no `<style>` at all and only reference
+//! its classes, sized and colored by CSS custom properties.
+#![cfg_attr(feature = "leptos", doc = "```rust")]
+#![cfg_attr(not(feature = "leptos"), doc = "```ignore")]
+//! use leptos::prelude::*;
+//! use spux::csp::provide_external_stylesheet;
+//!
+//! #[component]
+//! fn App() -> impl IntoView {
+//!     provide_external_stylesheet();
+//!
+//!     view! {
+//!         <link rel="stylesheet" href="/spux.css" />
+//!         // ...
+//!     }
+//! }
+//! ```
+//! The stylesheet covers every built-in component (the loaders, progress bars, skeletons, text
+//! loaders, grids, 3D loaders, `Compose` and the overlays), as well as the default shapes of `Morph`.
+//! Custom loaders are added to it with `spux::csp::stylesheet`, and a `Morph` through other shapes
+//! with the `css` of its `MorphConfig` (see `spux::core::render`).
+//!
+//! Rendered below it, no component renders a `<style>`:
+#![cfg_attr(
+    all(
+        feature = "ssr",
+        feature = "compose",
+        feature = "grids",
+        feature = "morphers",
+        feature = "progress",
+        feature = "pulsers",
+        feature = "skeletons",
+        feature = "text",
+        feature = "three_d"
+    ),
+    doc = "```rust"
+)]
+#![cfg_attr(
+    not(all(
+        feature = "ssr",
+        feature = "compose",
+        feature = "grids",
+        feature = "morphers",
+        feature = "progress",
+        feature = "pulsers",
+        feature = "skeletons",
+        feature = "text",
+        feature = "three_d"
+    )),
+    doc = "```ignore"
+)]
+//! use leptos::prelude::*;
+//! use spux::compose::{Compose, Layer};
+//! use spux::csp::provide_external_stylesheet;
+//! use spux::grids::{CubeGrid, FoldingCube};
+//! use spux::morphers::Morph;
+//! use spux::progress::{CircularProgress, LinearProgress};
+//! use spux::pulsers::Circle;
+//! use spux::skeletons::{SkeletonCard, SkeletonText};
+//! use spux::text::{Ellipsis, Scramble, ShimmerText, Typewriter};
+//! use spux::three_d::{CardFlip, Cube, FlipSquare};
+//!
+//! let owner = Owner::new();
+//! owner.set();
+//! provide_external_stylesheet();
+//!
+//! let html = view! {
+//!     <Circle size=12 />
+//!     <LinearProgress color="#000000" size=4 value=0.25 />
+//!     <CircularProgress color="#000000" size=24 />
+//!     <SkeletonText color="#e0e0e0" size=12 />
+//!     <SkeletonCard color="#e0e0e0" size=12 />
+//!     <Ellipsis text="Saving" />
+//!     <ShimmerText text="Preparing" />
+//!     <Typewriter text="Thinking" />
+//!     <Scramble text="Decoding" />
+//!     <CubeGrid size=6 />
+//!     <FoldingCube size=6 />
+//!     <Morph size=16 />
+//!     <FlipSquare size=16 />
+//!     <Cube size=16 />
+//!     <CardFlip size=16 />
+//!     <Compose size=24>
+//!         <Layer><Circle size=24 /></Layer>
+//!     </Compose>
+//! }
+//! .to_html();
+//!
+//! assert!(!html.contains("<style"));
+//! ```
+//! Nonces don't cover `style` attributes, so with the `nonce` feature or below
+//! `provide_external_stylesheet()`, the loaders (the spinners, the pulsers and the ones declared with
+//! `spux_loader!`) are styled by classes rather than by an inline style. The color, size and
+//! duration of a loader are classes (e.g `spux-size-24px`), whose rules are rendered into its
+//! `<style>`, or are added to the external stylesheet with `spux stylesheet --size 12 --size 24`
+//! (or `spux::csp::value_stylesheet()`). The other components, and the loaders rendered with
+//! `Renderer::WebAnimations`, still set their size and color (and e.g the value of a progress bar)
+//! in a `style` attribute, which the policy has to allow with `style-src-attr 'unsafe-inline'`.
+#![cfg_attr(
+    all(feature = "ssr", feature = "pulsers", feature = "spinners"),
+    doc = "```rust"
+)]
+#![cfg_attr(
+    not(all(feature = "ssr", feature = "pulsers", feature = "spinners")),
+    doc = "```ignore"
+)]
+//! use leptos::prelude::*;
+//! use spux::csp::{built_in_stylesheet, provide_external_stylesheet, value_stylesheet};
+//! use spux::pulsers::Circle;
+//! use spux::size::Size;
+//! use spux::spinners::Square;
+//!
+//! let owner = Owner::new();
+//! owner.set();
+//! provide_external_stylesheet();
+//!
+//! let html = view! {
+//!     <Circle size=12 />
+//!     <Square color="#112233" size=24 duration=900 />
+//! }
+//! .to_html();
+//! assert!(!html.contains("style=\""));
+//!
+//! let sizes = [Size::Px(12), Size::Px(24)];
+//! let css = built_in_stylesheet() + &value_stylesheet(&["#112233"], &sizes, &[900]).unwrap();
+//! for class in ["spux-color-112233", "spux-size-12px", "spux-size-24px", "spux-duration-900"] {
+//!     assert!(html.contains(class));
+//!     assert!(css.contains(&format!(".{class}{{")));
+//! }
+//! ```
+//! With the nonce, the rules are rendered into the `<style>` of the loader instead:
+#![cfg_attr(
+    all(feature = "nonce", feature = "ssr", feature = "pulsers"),
+    doc = "```rust"
+)]
+#![cfg_attr(
+    not(all(feature = "nonce", feature = "ssr", feature = "pulsers")),
+    doc = "```ignore"
+)]
+//! use leptos::nonce::provide_nonce;
+//! use leptos::prelude::*;
+//! use spux::pulsers::Circle;
+//!
+//! let owner = Owner::new();
+//! owner.set();
+//! provide_nonce();
+//!
+//! let html = view! { <Circle color="#112233" size=12 /> }.to_html();
+//! assert!(html.contains("<style nonce="));
+//! assert!(html.contains(".spux-color-112233{--spux-color:#112233}"));
+//! assert!(!html.contains("style=\""));
+//! ```
+//!
+//! # Islands
+//!
+//! With the `islands` feature (along with the one of Leptos), loaders fit the Leptos islands
+//! architecture. No Spux component is an island, so using one never turns its parent into an
+//! island: outside of islands, loaders are rendered as plain HTML and CSS, with no WASM and no
+//! hydration. They are still used inside of islands, and as `Suspense` fallbacks, like anywhere
+//! else. Components animated from Rust only animate inside of an island; outside of one,
+//! `Typewriter` and `Scramble` render their text fully revealed, and the Web Animations renderer
+//! falls back to CSS-animated SVG.
+#![cfg_attr(all(feature = "leptos", feature = "pulsers"), doc = "```rust")]
+#![cfg_attr(not(all(feature = "leptos", feature = "pulsers")), doc = "```ignore")]
+//! use leptos::prelude::*;
+//! use spux::pulsers::Circle;
+//!
+//! #[component]
+//! fn Article() -> impl IntoView {
+//!     view! {
+//!         <article>
+//!             // rendered on the server only
+//!             <Circle size=12 />
+//!         </article>
+//!     }
+//! }
+//! ```
+pub mod errors;
+mod types;
 
+mod macros;
+mod private;
 
- Axum: `cargo leptos new --git https://github.com/leptos-rs/start-axum`
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "leptos")]
+    pub use crate::private::render_loader;
+    pub use crate::private::IntoKeyframesCss;
+}
 
- Actix Web: `cargo leptos new --git https://github.com/leptos-rs/start-actix`
+#[cfg(feature = "pulsers")]
+pub mod pulsers;
 
- Lastly install and add Spux at the root of the new project
-```
-cargo add spux
-```
+#[cfg(feature = "spinners")]
+pub mod spinners;
 
-# Usage
+#[cfg(feature = "progress")]
+pub mod progress;
 
-Spux loaders are separated into `spinners` and `pulsers`.
+#[cfg(feature = "skeletons")]
+pub mod skeletons;
 
-v0.1.1 comes packed with:
+#[cfg(feature = "text")]
+pub mod text;
 
-`spinners`::
-* `Square`
-* `Triangle`
-* `FilledSquare`
-* `PartialCircle`
+#[cfg(feature = "morphers")]
+pub mod morphers;
 
-`pulsers`::
-* `Circle`
-* `Diamond`
-* `FilledCircle`
-* `FilledDiamond`
+#[cfg(feature = "grids")]
+pub mod grids;
 
-To use the various `spinners` and `pulsers`, enable them via features in your `Cargo.toml`:
-```toml
-[dependencies]
-spux = { version = "0.1.1", features = ["spinners", "pulsers"] }
-```
+#[cfg(feature = "three_d")]
+pub mod three_d;
 
+#[cfg(feature = "overlay")]
+pub mod overlay;
 
-Once Spux is installed, include the pulser or spinner that you want to use
-```rust
-use leptos::prelude::*;
-use spux::pulsers::Circle;
+#[cfg(feature = "compose")]
+pub mod compose;
 
-#[component]
-fn App() -> IntoView {
+#[cfg(feature = "canvas")]
+pub mod canvas;
 
-    view! {
-        <Circle color="#000000" size=10 />
-    }
-}
-```
+#[cfg(feature = "web_animations")]
+pub mod web_animations;
 
+#[cfg(feature = "raster")]
+pub mod raster;
 
-Each Spux component takes in required props for both `color` (#hex) and `size` (by px).
+#[cfg(feature = "terminal")]
+pub mod terminal;
 
-| Prop    | Type | Example   |
-| :------ | :--- | :-------- |
-| color   | &str | "#000000" |
-| size    | u32  | 15        |
+#[cfg(feature = "web-components")]
+pub mod web_components;
 
+#[cfg(feature = "head_styles")]
+pub mod head_styles;
 
-```rust
-use leptos::prelude::*;
-use spux::pulsers::Diamond;
+#[cfg(feature = "islands")]
+pub mod islands;
 
-#[component]
-fn App() -> IntoView {
+#[cfg(feature = "dioxus")]
+pub mod dioxus;
 
-    view! {
-        <Diamond color="#000000" size=10 />
-    }
-}
-```
-
-Spux components can also be used with `Suspense` in Leptos.
-```rust
-use leptos::prelude::*;
-use spux::spinners::FilledSquare;
-
-#[component]
-fn App() -> IntoView {
-
-  // posts_view consists of a server function that's being called to
-  // return a list of posts. see examples/basic-spinner on the GitHub page
-  // for more details
-  view! {
-    <Suspense fallback=move || view! {
-        <div
-            style:width="full"
-            style:margin-x="auto"
-            style:align-items="center"
-            style:justify-content="center"
-            style:display="flex"
-        >
-            <FilledSquare color="#000000" size=10 />
-        </div>
-    }>
-        <div>
-            <p>"Posts"</p>
-            <hr />
-            {posts_view}
-        </div>
-    </Suspense>
-
-  }
-}
-```
+#[cfg(feature = "yew")]
+pub mod yew;
 
-*/
-mod errors;
-mod types;
+#[cfg(feature = "sycamore")]
+pub mod sycamore;
 
-#[cfg(feature = "pulsers")]
-pub mod pulsers;
+pub mod core;
+pub mod csp;
+pub mod keyframes;
+pub mod loader;
+pub mod shapes;
+pub mod svg;
 
-#[cfg(feature = "spinners")]
-pub mod spinners;
+pub mod size;
@@ -0,0 +1,233 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+/*!
+ The definitions that loaders are rendered from.
+
+ Every loader declared with `spux_loader!` is rendered from a
+ [`LoaderDefinition`], which can also render the loader into standalone HTML
+ and CSS (e.g for pages that aren't built with Leptos), so that the loaders
+ stay identical everywhere.
+
+```
+use spux::loader::LoaderDefinition;
+
+let dot = LoaderDefinition::new(
+    "brand-dot",
+    ".brand-dot { width: var(--spux-size); height: var(--spux-size); }",
+    "@keyframes brand-dot{0%{opacity:1}100%{opacity:0}}",
+);
+
+let html = dot.html("#000000", 24, None, "Loading").unwrap();
+assert!(html.contains("<span class=\"brand-dot\" style=\"--spux-index: 0\"></span>"));
+assert!(dot.html("#000000", 0, None, "Loading").is_err());
+```
+*/
+use crate::core::{self, LoaderConfig};
+use crate::errors::ValidationError;
+use crate::size::Size;
+use crate::svg::{Renderer, SvgLoader};
+use crate::types::Validation;
+
+/// The definition of a loader, see the [module documentation](self).
+///
+/// The loader is made up of `elements` elements with the given `class`,
+/// inside of a box of `size` by `size` (enlarged by `scale`), and is styled
+/// by `style` and `keyframes` (both plain CSS).
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoaderDefinition {
+    class: &'static str,
+    style: &'static str,
+    keyframes: String,
+    elements: u32,
+    scale: u32,
+    duration: u32,
+    svg: Option<SvgLoader>,
+    glyphs: Option<&'static [&'static str]>,
+}
+
+impl LoaderDefinition {
+    /// A loader of one element, with a duration of 1400ms.
+    pub fn new(class: &'static str, style: &'static str, keyframes: impl Into<String>) -> Self {
+        LoaderDefinition {
+            class,
+            style,
+            keyframes: keyframes.into(),
+            elements: 1,
+            scale: 1,
+            duration: 1400,
+            svg: None,
+            glyphs: None,
+        }
+    }
+
+    pub fn elements(self, elements: u32) -> Self {
+        LoaderDefinition { elements, ..self }
+    }
+
+    pub fn scale(self, scale: u32) -> Self {
+        LoaderDefinition { scale, ..self }
+    }
+
+    /// The default duration (by ms) of one cycle of the animation.
+    pub fn duration(self, duration: u32) -> Self {
+        LoaderDefinition { duration, ..self }
+    }
+
+    /// The SVG drawing of the loader, see the [`svg`](crate::svg) module.
+    pub fn svg(self, svg: SvgLoader) -> Self {
+        LoaderDefinition {
+            svg: Some(svg),
+            ..self
+        }
+    }
+
+    /// The frames of the loader in a terminal, one glyph each, see the
+    /// `terminal` module.
+    pub fn glyphs(self, glyphs: &'static [&'static str]) -> Self {
+        LoaderDefinition {
+            glyphs: Some(glyphs),
+            ..self
+        }
+    }
+
+    pub fn class(&self) -> &'static str {
+        self.class
+    }
+
+    pub fn element_count(&self) -> u32 {
+        self.elements
+    }
+
+    pub fn default_duration(&self) -> u32 {
+        self.duration
+    }
+
+    pub fn svg_loader(&self) -> Option<&SvgLoader> {
+        self.svg.as_ref()
+    }
+
+    pub fn terminal_glyphs(&self) -> Option<&'static [&'static str]> {
+        self.glyphs
+    }
+
+    /// The stylesheet of the loader, shared by every instance of it.
+    pub fn css(&self) -> String {
+        let style = self.style.split_whitespace().collect::<Vec<_>>().join(" ");
+        format!("{style}\n{}", self.keyframes)
+    }
+
+    // the inline style of the outermost element of a loader, which sizes it
+    // and sets the custom properties its stylesheet is written against
+    pub(crate) fn root_style(
+        &self,
+        color: &str,
+        size: Size,
+        duration: u32,
+        scaled: bool,
+    ) -> String {
+        let box_size = if scaled {
+            size.scaled(self.scale)
+        } else {
+            size
+        };
+        format!(
+            "--spux-color: {color}; --spux-size: {size}; --spux-duration: {duration}ms; \
+             display: {}; vertical-align: {}; flex-shrink: 0; width: {box_size}; height: {box_size}",
+            size.display(),
+            size.vertical_align()
+        )
+    }
+
+    // the rules that take the place of the inline styles of the elements of a
+    // loader that is styled by classes (see `LoaderConfig::class_styled`)
+    pub(crate) fn class_css(&self) -> String {
+        let indices = (0..self.elements)
+            .map(|index| {
+                format!(
+                    ".{}:nth-child({}){{--spux-index:{index}}}",
+                    self.class,
+                    index + 1
+                )
+            })
+            .collect::<String>();
+        format!(
+            ".spux-loader:has(> .{}){{--spux-scale:{}}}{indices}",
+            self.class, self.scale
+        )
+    }
+
+    /// Renders a standalone stylesheet for the markup of [`html`](Self::html)
+    /// (without its `<style>`), with the given color (#hex), size and
+    /// duration (by ms, defaults to the duration of the loader).
+    pub fn stylesheet(
+        &self,
+        color: &str,
+        size: impl Into<Size>,
+        duration: Option<u32>,
+    ) -> Result<String, ValidationError> {
+        color.validate_color_code()?;
+        let size = size.into();
+        size.validate()?;
+        let root_style = self.root_style(color, size, duration.unwrap_or(self.duration), true);
+        Ok(format!(
+            ".spux-loader:has(> .{}) {{ {root_style} }}\n{}",
+            self.class,
+            self.css()
+        ))
+    }
+
+    /// Renders the loader into standalone HTML (including its `<style>`),
+    /// with the given color (#hex), size, duration (by ms, defaults to the
+    /// duration of the loader) and label, see [`core::render`] for rendering
+    /// it as SVG.
+    pub fn html(
+        &self,
+        color: &str,
+        size: impl Into<Size>,
+        duration: Option<u32>,
+        label: &str,
+    ) -> Result<String, ValidationError> {
+        let mut config = LoaderConfig::new(self.clone(), size)
+            .color(color)
+            .label(label)
+            .renderer(Renderer::Css);
+        if let Some(duration) = duration {
+            config = config.duration(duration);
+        }
+        let rendered = core::render(config)?;
+        Ok(format!("<style>{}</style>{}", rendered.css, rendered.html))
+    }
+}
+
+/// The built-in loaders of the enabled `spinners` and `pulsers` features, by
+/// their kebab-case names (e.g `filled-circle`).
+#[allow(unused_mut)]
+pub fn built_in() -> Vec<(&'static str, LoaderDefinition)> {
+    let mut loaders = Vec::new();
+    #[cfg(feature = "spinners")]
+    {
+        use crate::spinners::*;
+        loaders.extend([
+            ("square", square_definition()),
+            ("filled-square", filled_square_definition()),
+            ("partial-circle", partial_circle_definition()),
+            ("triangle", triangle_definition()),
+        ]);
+    }
+    #[cfg(feature = "pulsers")]
+    {
+        use crate::pulsers::*;
+        loaders.extend([
+            ("circle", circle_definition()),
+            ("filled-circle", filled_circle_definition()),
+            ("diamond", diamond_definition()),
+            ("filled-diamond", filled_diamond_definition()),
+        ]);
+    }
+    loaders
+}
@@ -0,0 +1,190 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+/// Declares a custom loader as a Leptos component that behaves exactly
+/// like the loaders that come with Spux.
+///
+/// The generated component takes the standard props of every Spux loader:
+/// a required `size` (by px, or by em to render it inline), an optional
+/// `color` (#hex) that defaults to the color of the surrounding text, an
+/// optional `duration` (by ms), an optional `label` that is announced to
+/// assistive technologies (defaults to "Loading") and an optional `renderer`.
+///
+/// The loader is made up of `elements` (defaults to 1) elements with the
+/// given `class`, inside of a box of `size` by `size` (which can be enlarged
+/// with `scale`). The `style` is plain CSS and the `keyframes` are either plain
+/// CSS or built with [`Keyframes`](crate::keyframes::Keyframes). Both can use
+/// the following custom properties, so one stylesheet serves every instance of
+/// the loader no matter its size or color:
+///
+/// * `--spux-color` - the color of the loader
+/// * `--spux-size` - the size of the loader
+/// * `--spux-duration` - the duration of one cycle of the animation
+/// * `--spux-index` - the index of the element, starting from 0
+///
+/// An optional `svg` ([`SvgLoader`](crate::svg::SvgLoader)) lets the loader
+/// render as SVG, see the [`svg`](crate::svg) module. Loaders without one
+/// render as elements by default, and exit with an error when
+/// `renderer=Renderer::Svg` is passed. Optional `glyphs` (e.g
+/// `&["·", "•", "●", "•"]`) are the frames of the loader in a terminal.
+///
+/// Naming a function after the loader (e.g `pub BrandDots => brand_dots`)
+/// also declares a function that returns the
+/// [`LoaderDefinition`](crate::loader::LoaderDefinition) of the loader, e.g
+/// to render it outside of Leptos.
+///
+/// The component is only declared with the `leptos` feature (enabled by
+/// default), while the definition is always declared, e.g for the
+/// `SpuxLoader` component of the `dioxus`, `yew` and `sycamore` features.
+///
+/// # Example Usage
+/// ```
+/// use leptos::prelude::*;
+/// use spux::keyframes::{Keyframes, Unit};
+/// use spux::spux_loader;
+///
+/// spux_loader! {
+///     /// Renders three dots that fade in and out one after the other.
+///     pub BrandDots => brand_dots {
+///         class: "brand-dots",
+///         elements: 3,
+///         style: "
+///             .brand-dots {
+///                 width: calc(var(--spux-size) / 4);
+///                 height: calc(var(--spux-size) / 4);
+///                 margin: auto;
+///                 border-radius: 100%;
+///                 background-color: var(--spux-color);
+///                 animation: brand-dots var(--spux-duration) ease-in-out infinite;
+///                 animation-delay: calc(var(--spux-index) * var(--spux-duration) / 6);
+///             }
+///         ",
+///         keyframes: Keyframes::new("brand-dots")
+///             .at(0.pct(), |step| step.opacity(0.2))
+///             .at(50.pct(), |step| step.opacity(1.0))
+///             .at(100.pct(), |step| step.opacity(0.2)),
+///     }
+/// }
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <BrandDots color="#000000" size=24 label="Saving" />
+///     }
+/// }
+///
+/// let html = brand_dots().html("#000000", 24, None, "Saving").unwrap();
+/// ```
+#[macro_export]
+macro_rules! spux_loader {
+    (@or , $default:expr) => {
+        $default
+    };
+    (@or $value:expr, $default:expr) => {
+        $value
+    };
+    (@definition [
+        $class:literal,
+        [$($elements:expr)?],
+        [$($scale:expr)?],
+        [$($duration:expr)?],
+        [$($svg:expr)?],
+        [$($glyphs:expr)?],
+        $style:expr,
+        $keyframes:expr
+    ]) => {
+        $crate::loader::LoaderDefinition::new(
+            $class,
+            $style,
+            $crate::__private::IntoKeyframesCss::into_keyframes_css($keyframes),
+        )
+        $(.elements($elements))?
+        $(.scale($scale))?
+        $(.duration($duration))?
+        $(.svg($svg))?
+        $(.glyphs($glyphs))?
+    };
+    (@export [$($vis:vis $definition:ident)?] $name:ident $fields:tt) => {
+        $(
+            #[doc = ::std::concat!(
+                "The definition of the [`", ::std::stringify!($name), "`] loader, ",
+                "see the [`loader`](", ::std::stringify!($crate), "::loader) module."
+            )]
+            $vis fn $definition() -> $crate::loader::LoaderDefinition {
+                $crate::spux_loader!(@definition $fields)
+            }
+        )?
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis $name:ident $(=> $definition:ident)? {
+            class: $class:literal,
+            $(elements: $elements:expr,)?
+            $(scale: $scale:expr,)?
+            $(duration: $duration:expr,)?
+            $(svg: $svg:expr,)?
+            $(glyphs: $glyphs:expr,)?
+            style: $style:expr,
+            keyframes: $keyframes:expr $(,)?
+        }
+    ) => {
+        $crate::spux_loader!(@export [$($vis $definition)?] $name [
+            $class,
+            [$($elements)?],
+            [$($scale)?],
+            [$($duration)?],
+            [$($svg)?],
+            [$($glyphs)?],
+            $style,
+            $keyframes
+        ]);
+
+        $crate::__spux_leptos_component! {
+            $(#[$meta])*
+            $vis $name [
+                $class,
+                [$($elements)?],
+                [$($scale)?],
+                [$($duration)?],
+                [$($svg)?],
+                [$($glyphs)?],
+                $style,
+                $keyframes
+            ]
+        }
+    };
+}
+
+// the component is declared by a macro of its own, so that it is only declared
+// when spux itself is built with the `leptos` feature (rather than the crate
+// that `spux_loader!` is used from)
+#[cfg(feature = "leptos")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __spux_leptos_component {
+    ($(#[$meta:meta])* $vis:vis $name:ident $fields:tt) => {
+        $(#[$meta])*
+        #[::leptos::component]
+        $vis fn $name(
+            #[prop(default = None, into)] color: Option<&'static str>,
+            #[prop(into)] size: $crate::size::Size,
+            #[prop(optional)] duration: Option<u32>,
+            #[prop(default = "Loading")] label: &'static str,
+            #[prop(optional)] renderer: Option<$crate::svg::Renderer>,
+        ) -> impl ::leptos::IntoView {
+            let definition = $crate::spux_loader!(@definition $fields);
+            $crate::__private::render_loader(definition, color, size, duration, label, renderer)
+        }
+    };
+}
+
+#[cfg(not(feature = "leptos"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __spux_leptos_component {
+    ($($tokens:tt)*) => {};
+}
@@ -0,0 +1,51 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+mod morph;
+#[cfg(feature = "leptos")]
+pub use morph::Morph;
+pub use morph::MorphConfig;
+
+use crate::keyframes::{KeyframeStep, Keyframes, Unit};
+use crate::shapes::Shape;
+
+// builds the keyframes that move through every shape in order (and back to the
+// first one), holding each shape for a moment before morphing into the next
+fn morph_keyframes(name: &str, shapes: &[Shape]) -> Keyframes {
+    let count = shapes.len().max(1) as f64;
+    let step = |shape: Shape| {
+        move |step: KeyframeStep| {
+            step.border_radius(shape.border_radius())
+                .clip_path(&shape.clip_path())
+                .rotate(shape.rotation())
+        }
+    };
+
+    let keyframes =
+        shapes
+            .iter()
+            .enumerate()
+            .fold(Keyframes::new(name), |keyframes, (index, shape)| {
+                let start = index as f64 / count * 100.0;
+                let hold = start + 100.0 / count / 2.0;
+                keyframes
+                    .at(start.pct(), step(*shape))
+                    .at(hold.pct(), step(*shape))
+            });
+    match shapes.first() {
+        Some(first) => keyframes.at(100.pct(), step(*first)),
+        None => keyframes,
+    }
+}
+
+// a unique name per shape sequence so that morphers with different
+// sequences on the same page don't override each others keyframes
+fn morph_name(prefix: &str, shapes: &[Shape]) -> String {
+    shapes
+        .iter()
+        .fold(prefix.to_string(), |name, shape| name + "-" + shape.name())
+}
@@ -0,0 +1,185 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+use super::{morph_keyframes, morph_name};
+use crate::core::{loader_color, Render, RenderedLoader};
+use crate::errors::ValidationError;
+#[cfg(feature = "leptos")]
+use crate::private::{loader_style, render_config};
+use crate::shapes::Shape;
+use crate::size::Size;
+#[cfg(feature = "leptos")]
+use leptos::prelude::*;
+
+/// A filled shape that morphs through a sequence of shapes, as rendered by
+/// the `Morph` component, see the [`core`](crate::core) module.
+///
+/// Takes in a size (by px, or by em to render it inline). The optional color
+/// (#hex) defaults to the color of the surrounding text, `shapes` defaults to
+/// a square, a circle and a diamond, `duration` (by ms, spent on each shape)
+/// defaults to 1400 and `label` defaults to "Loading".
+///
+/// # Example Usage
+/// ```
+/// use spux::core::render;
+/// use spux::morphers::MorphConfig;
+/// use spux::shapes::Shape;
+///
+/// let morph = MorphConfig::new(16).shapes([Shape::Circle, Shape::Triangle]);
+/// let rendered = render(morph).unwrap();
+/// assert_eq!(rendered.class_names, ["spux-morph-circle-triangle"]);
+/// assert!(render(MorphConfig::new(16).shapes([])).is_err());
+/// assert!(render(MorphConfig::new(16).duration(u32::MAX)).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MorphConfig {
+    color: Option<String>,
+    size: Size,
+    shapes: Vec<Shape>,
+    duration: u32,
+    label: String,
+}
+
+impl MorphConfig {
+    pub fn new(size: impl Into<Size>) -> Self {
+        MorphConfig {
+            color: None,
+            size: size.into(),
+            shapes: vec![Shape::Square, Shape::Circle, Shape::Diamond],
+            duration: 1400,
+            label: "Loading".to_string(),
+        }
+    }
+
+    pub fn color(self, color: impl Into<String>) -> Self {
+        MorphConfig {
+            color: Some(color.into()),
+            ..self
+        }
+    }
+
+    pub fn shapes(self, shapes: impl Into<Vec<Shape>>) -> Self {
+        MorphConfig {
+            shapes: shapes.into(),
+            ..self
+        }
+    }
+
+    pub fn duration(self, duration: u32) -> Self {
+        MorphConfig { duration, ..self }
+    }
+
+    pub fn label(self, label: impl Into<String>) -> Self {
+        MorphConfig {
+            label: label.into(),
+            ..self
+        }
+    }
+}
+
+impl Render for MorphConfig {
+    fn render(self) -> Result<RenderedLoader, ValidationError> {
+        let color = loader_color(self.color.as_deref())?;
+        self.size.validate()?;
+        if self.shapes.is_empty() {
+            return Err(ValidationError::new(
+                "Morph should be given at least one shape",
+            ));
+        }
+
+        let name = morph_name("spux-morph", &self.shapes);
+        let keyframes = morph_keyframes(&name, &self.shapes).to_css()?;
+        // the animation spends `duration` on every shape, which has to fit
+        // into a single CSS duration
+        let total_duration = u32::try_from(self.shapes.len())
+            .ok()
+            .and_then(|count| self.duration.checked_mul(count))
+            .ok_or_else(|| {
+                ValidationError::new("Morph duration is too long for the number of shapes")
+            })?;
+        let css = format!(
+            "
+            .{name} {{
+                animation: {name} {total_duration}ms ease-in-out forwards;
+                animation-iteration-count: infinite;
+            }}
+
+            {keyframes}
+        "
+        );
+
+        let size = self.size;
+        let style = format!(
+            "display: {}; vertical-align: {}; width: {size}; height: {size}; background-color: {color}",
+            size.display(),
+            size.vertical_align()
+        );
+
+        Ok(RenderedLoader::new(
+            "span",
+            &[
+                ("class", &name),
+                ("role", "status"),
+                ("aria-label", &self.label),
+            ],
+            style,
+            String::new(),
+            &css,
+            &[&name],
+        ))
+    }
+}
+
+/// Renders a filled shape that morphs into the next shape of the
+/// sequence, looping back to the first one.
+///
+/// Takes in required props for size (by px, or by em to render it inline) and
+/// an optional color (#hex), which defaults to the color of the surrounding text.
+/// `shapes` defaults to a square, a circle and a diamond, while `duration`
+/// (by ms) is the time spent on each shape and defaults to 1400. `label`
+/// (announced to assistive technologies) defaults to "Loading".
+///
+/// # Example Usage
+/// ```
+/// use leptos::prelude::*;
+/// use spux::morphers::Morph;
+/// use spux::shapes::Shape;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <Morph color="#000000" size=16 shapes=[Shape::Square, Shape::Circle, Shape::Triangle] />
+///     }
+/// }
+/// ```
+#[cfg(feature = "leptos")]
+#[component]
+pub fn Morph(
+    #[prop(default = None, into)] color: Option<&'static str>,
+    #[prop(into)] size: Size,
+    #[prop(into, default = vec![Shape::Square, Shape::Circle, Shape::Diamond])] shapes: Vec<Shape>,
+    #[prop(default = 1400)] duration: u32,
+    #[prop(default = "Loading")] label: &'static str,
+) -> impl IntoView {
+    let config = MorphConfig::new(size)
+        .shapes(shapes)
+        .duration(duration)
+        .label(label);
+    let rendered = render_config(match color {
+        Some(color) => config.color(color),
+        None => config,
+    });
+    let class = rendered.class_names[0].clone();
+
+    view! {
+        {loader_style(rendered.css)}
+        <span class=class
+            role="status"
+            aria-label=label
+            style=rendered.style
+        ></span>
+    }
+}
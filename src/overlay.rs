@@ -0,0 +1,21 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+mod spux_overlay;
+pub use spux_overlay::SpuxOverlay;
+pub(crate) use spux_overlay::STYLE as SPUX_OVERLAY_STYLE;
+
+mod loading_overlay;
+pub use loading_overlay::LoadingOverlay;
+pub(crate) use loading_overlay::STYLE as LOADING_OVERLAY_STYLE;
+
+// the #hex color of the backdrop is combined with its opacity into a
+// #hex color with an alpha channel (e.g #ffffffcc)
+fn backdrop_color(color: &str, opacity: f64) -> String {
+    let alpha = (opacity.clamp(0.0, 1.0) * 255.0).round() as u8;
+    format!("{color}{alpha:02x}")
+}
@@ -0,0 +1,159 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+use super::backdrop_color;
+use crate::private::loader_style;
+use crate::types::Validation;
+use leptos::portal::Portal;
+use leptos::prelude::*;
+use leptos::tachys::html::element::ElementType;
+use send_wrapper::SendWrapper;
+use std::process;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, ResizeObserver};
+
+pub(crate) const STYLE: &str = "
+    .spux-loading-overlay {
+        position: fixed;
+        display: flex;
+        align-items: center;
+        justify-content: center;
+        overflow: hidden;
+        pointer-events: all;
+    }
+";
+
+/// Renders a loader over a single element of the page (e.g a card, a
+/// table or a chart) while `show` is true, leaving the rest of the page
+/// interactive.
+///
+/// The overlay follows the bounding box of the `target` as it is resized
+/// or scrolled, and marks the `target` with `aria-busy` while it is shown,
+/// restoring whatever `aria-busy` the `target` had before once it is hidden.
+/// The overlay is announced to assistive technologies with its `label`,
+/// which defaults to "Loading".
+///
+/// The `backdrop` (#hex) defaults to white with an `opacity` of 0.8.
+///
+/// # Example Usage
+/// ```
+/// use leptos::html::Div;
+/// use leptos::prelude::*;
+/// use spux::overlay::LoadingOverlay;
+/// use spux::spinners::PartialCircle;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let chart = NodeRef::<Div>::new();
+///     let refetching = RwSignal::new(true);
+///
+///     view! {
+///         <div node_ref=chart>"Chart"</div>
+///         <LoadingOverlay target=chart show=refetching>
+///             <PartialCircle color="#000000" size=16 />
+///         </LoadingOverlay>
+///     }
+/// }
+/// ```
+
+#[component]
+pub fn LoadingOverlay<E>(
+    target: NodeRef<E>,
+    #[prop(into)] show: Signal<bool>,
+    #[prop(default = "#ffffff")] backdrop: &'static str,
+    #[prop(default = 0.8)] opacity: f64,
+    #[prop(default = "Loading")] label: &'static str,
+    children: ChildrenFn,
+) -> impl IntoView
+where
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+{
+    // we intentionally create a compile time process exit to inform users
+    // that an incorrect #hex color code was used
+    let Ok(_) = backdrop.validate_color_code() else {
+        eprintln!("Spux Error - Backdrop should be in the #hex format (e.g #000000) for black");
+        process::exit(1);
+    };
+
+    let background = StoredValue::new(backdrop_color(backdrop, opacity));
+    let children = StoredValue::new(children);
+
+    // the viewport position of the target as (top, left, width, height)
+    let bounds = RwSignal::new((0.0, 0.0, 0.0, 0.0));
+
+    Effect::new(move |_| {
+        if !show.get() {
+            return;
+        }
+        let Some(target) = target.get() else {
+            return;
+        };
+        let target = target.unchecked_into::<Element>();
+
+        let measure = {
+            let target = target.clone();
+            move || {
+                let rect = target.get_bounding_client_rect();
+                bounds.set((rect.top(), rect.left(), rect.width(), rect.height()));
+            }
+        };
+        measure();
+        let busy = target.get_attribute("aria-busy");
+        _ = target.set_attribute("aria-busy", "true");
+
+        // the target is measured again whenever it is resized, or whenever
+        // the page (or any of its scroll containers) is scrolled or resized
+        let on_change = Closure::<dyn Fn()>::new(measure);
+        let callback = on_change.as_ref().unchecked_ref();
+        let observer = ResizeObserver::new(callback).ok();
+        if let Some(observer) = &observer {
+            observer.observe(&target);
+        }
+        let window = window();
+        _ = window.add_event_listener_with_callback_and_bool("scroll", callback, true);
+        _ = window.add_event_listener_with_callback("resize", callback);
+
+        // the cleanup belongs to the owner of the effect, which runs it before
+        // every re-run of the effect (e.g when `show` changes) as well as when
+        // the overlay is unmounted, so that nothing is left behind either way
+        let cleanup = SendWrapper::new((target, busy, observer, on_change));
+        on_cleanup(move || {
+            let (target, busy, observer, on_change) = cleanup.take();
+            let callback = on_change.as_ref().unchecked_ref();
+            let window = leptos::prelude::window();
+            _ = window.remove_event_listener_with_callback_and_bool("scroll", callback, true);
+            _ = window.remove_event_listener_with_callback("resize", callback);
+            if let Some(observer) = observer {
+                observer.disconnect();
+            }
+            _ = match busy {
+                Some(busy) => target.set_attribute("aria-busy", &busy),
+                None => target.remove_attribute("aria-busy"),
+            };
+        });
+    });
+
+    view! {
+        <Show when=move || show.get()>
+            <Portal>
+                {loader_style(STYLE.to_string())}
+                <div class="spux-loading-overlay"
+                    role="status"
+                    aria-label=label
+                    style:top=move || format!("{}px", bounds.get().0)
+                    style:left=move || format!("{}px", bounds.get().1)
+                    style:width=move || format!("{}px", bounds.get().2)
+                    style:height=move || format!("{}px", bounds.get().3)
+                    style:background-color=background.get_value()
+                >
+                    {children.with_value(|children| children())}
+                </div>
+            </Portal>
+        </Show>
+    }
+}
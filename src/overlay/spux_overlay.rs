@@ -0,0 +1,198 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+use super::backdrop_color;
+use crate::private::loader_style;
+use crate::types::Validation;
+use leptos::html::Div;
+use leptos::portal::Portal;
+use leptos::prelude::*;
+use std::process;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{HtmlElement, KeyboardEvent};
+
+pub(crate) const STYLE: &str = "
+    .spux-overlay {
+        position: fixed;
+        top: 0px;
+        right: 0px;
+        bottom: 0px;
+        left: 0px;
+        z-index: 2147483647;
+        display: flex;
+        flex-direction: column;
+        align-items: center;
+        justify-content: center;
+        gap: 12px;
+        pointer-events: all;
+        outline: none;
+    }
+";
+
+/// Renders a full-screen overlay with a centered loader (and an optional
+/// message) that blocks the rest of the page while `show` is true.
+///
+/// The overlay is rendered at the end of `<body>`. While it is shown it
+/// blocks pointer events, traps the keyboard focus (tabbing cycles through
+/// the focusable elements of its children) and locks the scrolling of the
+/// page. Once it is hidden again, the focus is restored to the element
+/// that had it before the overlay was shown.
+///
+/// The `backdrop` (#hex) defaults to white with an `opacity` of 0.8.
+///
+/// # Example Usage
+/// ```
+/// use leptos::prelude::*;
+/// use spux::overlay::SpuxOverlay;
+/// use spux::pulsers::Circle;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let deleting = RwSignal::new(false);
+///
+///     view! {
+///         <SpuxOverlay show=deleting message="Deleting your account">
+///             <Circle color="#000000" size=24 />
+///         </SpuxOverlay>
+///     }
+/// }
+/// ```
+
+#[component]
+pub fn SpuxOverlay(
+    #[prop(into)] show: Signal<bool>,
+    #[prop(optional, into)] message: MaybeProp<String>,
+    #[prop(default = "#ffffff")] backdrop: &'static str,
+    #[prop(default = 0.8)] opacity: f64,
+    children: ChildrenFn,
+) -> impl IntoView {
+    // we intentionally create a compile time process exit to inform users
+    // that an incorrect #hex color code was used
+    let Ok(_) = backdrop.validate_color_code() else {
+        eprintln!("Spux Error - Backdrop should be in the #hex format (e.g #000000) for black");
+        process::exit(1);
+    };
+
+    let overlay_ref = NodeRef::<Div>::new();
+    let background = StoredValue::new(backdrop_color(backdrop, opacity));
+    let children = StoredValue::new(children);
+
+    // the element that had the focus and the overflow of the body before the
+    // overlay was shown, so that both can be restored once it is hidden
+    let restore = StoredValue::new_local(None::<(Option<HtmlElement>, String)>);
+
+    let lock = move || {
+        let document = document();
+        let focused = document
+            .active_element()
+            .and_then(|element| element.dyn_into::<HtmlElement>().ok());
+        let overflow = document.body().map(|body| {
+            let overflow = body
+                .style()
+                .get_property_value("overflow")
+                .unwrap_or_default();
+            _ = body.style().set_property("overflow", "hidden");
+            overflow
+        });
+        restore.set_value(Some((focused, overflow.unwrap_or_default())));
+    };
+
+    let unlock = move || {
+        let Some((focused, overflow)) = restore.try_update_value(Option::take).flatten() else {
+            return;
+        };
+        if let Some(body) = document().body() {
+            _ = body.style().set_property("overflow", &overflow);
+        }
+        if let Some(focused) = focused {
+            _ = focused.focus();
+        }
+    };
+
+    Effect::new(move |_| match show.get() {
+        true => lock(),
+        false => unlock(),
+    });
+    on_cleanup(move || {
+        if restore.try_with_value(Option::is_some).unwrap_or(false) {
+            unlock();
+        }
+    });
+
+    // once the overlay is mounted it takes the focus away from the page
+    Effect::new(move |_| {
+        if let Some(overlay) = overlay_ref.get() {
+            _ = overlay.focus();
+        }
+    });
+
+    // tabbing cycles through the elements of the overlay (e.g a cancel button
+    // passed as one of its children), or keeps the focus on the overlay when
+    // there are none, instead of letting it escape to the page
+    let trap_focus = move |event: KeyboardEvent| {
+        if event.key() != "Tab" {
+            return;
+        }
+        let Some(overlay) = overlay_ref.get() else {
+            return;
+        };
+        let focusable = focusable_elements(&overlay);
+        let (Some(first), Some(last)) = (focusable.first(), focusable.last()) else {
+            event.prevent_default();
+            _ = overlay.focus();
+            return;
+        };
+
+        let active = document().active_element().map(JsValue::from);
+        let is_active = |element: &HtmlElement| active.as_ref() == Some(element.as_ref());
+        let on_overlay = active.as_ref() == Some(overlay.as_ref());
+        let next = match event.shift_key() {
+            true if on_overlay || is_active(first) => Some(last),
+            false if is_active(last) => Some(first),
+            _ => None,
+        };
+        if let Some(next) = next {
+            event.prevent_default();
+            _ = next.focus();
+        }
+    };
+
+    view! {
+        <Show when=move || show.get()>
+            <Portal>
+                {loader_style(STYLE.to_string())}
+                <div class="spux-overlay"
+                    node_ref=overlay_ref
+                    role="alertdialog"
+                    aria-modal="true"
+                    aria-busy="true"
+                    aria-label=move || message.get().unwrap_or_else(|| "Loading".to_string())
+                    tabindex="-1"
+                    style:background-color=background.get_value()
+                    on:keydown=trap_focus
+                >
+                    {children.with_value(|children| children())}
+                    {move || message.get().map(|message| view! { <p role="status">{message}</p> })}
+                </div>
+            </Portal>
+        </Show>
+    }
+}
+
+// the elements that can be tabbed to, in the order of the document
+const FOCUSABLE: &str = "a[href], area[href], button:not([disabled]), \
+    input:not([disabled]):not([type=\"hidden\"]), select:not([disabled]), \
+    textarea:not([disabled]), iframe, [contenteditable], [tabindex]:not([tabindex=\"-1\"])";
+
+fn focusable_elements(overlay: &HtmlElement) -> Vec<HtmlElement> {
+    let Ok(elements) = overlay.query_selector_all(FOCUSABLE) else {
+        return Vec::new();
+    };
+    (0..elements.length())
+        .filter_map(|index| elements.get(index))
+        .filter_map(|element| element.dyn_into::<HtmlElement>().ok())
+        .collect()
+}
@@ -0,0 +1,247 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+#[cfg(any(
+    feature = "leptos",
+    feature = "dioxus",
+    feature = "yew",
+    feature = "sycamore"
+))]
+use crate::core::{self, LoaderConfig, Render, RenderedLoader};
+#[cfg(feature = "leptos")]
+use crate::csp::ExternalStylesheet;
+use crate::keyframes::Keyframes;
+#[cfg(any(
+    feature = "leptos",
+    feature = "dioxus",
+    feature = "yew",
+    feature = "sycamore"
+))]
+use crate::loader::LoaderDefinition;
+#[cfg(any(
+    feature = "leptos",
+    feature = "dioxus",
+    feature = "yew",
+    feature = "sycamore"
+))]
+use crate::size::Size;
+#[cfg(any(
+    feature = "leptos",
+    feature = "dioxus",
+    feature = "yew",
+    feature = "sycamore"
+))]
+use crate::svg::Renderer;
+#[cfg(feature = "leptos")]
+use leptos::prelude::*;
+use std::process;
+
+// validates the optional #hex color of a loader, falling back to the
+// color of the surrounding text when none was given
+#[cfg(any(feature = "canvas", feature = "web_animations"))]
+pub fn resolve_color(color: Option<&'static str>) -> &'static str {
+    use crate::types::Validation;
+
+    // we intentionally create a compile time process exit to inform users
+    // that an incorrect #hex color code was used
+    if let Some(Err(_)) = color.map(|color| color.validate_color_code()) {
+        eprintln!("Spux Error - Color should be in the #hex format (e.g #000000) for black");
+        process::exit(1);
+    }
+    color.unwrap_or("currentColor")
+}
+
+// validates the size of a loader, which is written into its CSS
+#[cfg(feature = "web_animations")]
+pub fn resolve_size(size: Size) -> Size {
+    // we intentionally create a compile time process exit to inform users
+    // that a size which can't be rendered was used
+    if let Err(error) = size.validate() {
+        eprintln!("Spux Error - {error}");
+        process::exit(1);
+    }
+    size
+}
+
+// the keyframes of a loader, either as plain CSS or built with `Keyframes`
+pub trait IntoKeyframesCss {
+    fn into_keyframes_css(self) -> String;
+}
+
+impl IntoKeyframesCss for &str {
+    fn into_keyframes_css(self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoKeyframesCss for String {
+    fn into_keyframes_css(self) -> String {
+        self
+    }
+}
+
+impl IntoKeyframesCss for Keyframes {
+    fn into_keyframes_css(self) -> String {
+        // same as with colors, invalid keyframes exit the process rather than
+        // rendering a loader that silently doesn't animate
+        self.to_css().unwrap_or_else(|error| {
+            eprintln!("Spux Error - {error} (in @keyframes {})", self.name());
+            process::exit(1);
+        })
+    }
+}
+
+// the config of a loader from the props of its component, which are the same
+// for every framework
+#[cfg(any(
+    feature = "leptos",
+    feature = "dioxus",
+    feature = "yew",
+    feature = "sycamore"
+))]
+pub(crate) fn loader_config(
+    definition: LoaderDefinition,
+    color: Option<&str>,
+    size: Size,
+    duration: Option<u32>,
+    label: &str,
+    renderer: Option<Renderer>,
+) -> LoaderConfig {
+    let mut config = LoaderConfig::new(definition, size).label(label);
+    if let Some(renderer) = renderer {
+        config = config.renderer(renderer);
+    }
+    if let Some(color) = color {
+        config = config.color(color);
+    }
+    if let Some(duration) = duration {
+        config = config.duration(duration);
+    }
+    config
+}
+
+// same as with colors, a loader (or any other component) that can't be
+// rendered exits the process
+#[cfg(any(
+    feature = "leptos",
+    feature = "dioxus",
+    feature = "yew",
+    feature = "sycamore"
+))]
+pub(crate) fn render_config(config: impl Render) -> RenderedLoader {
+    core::render(config).unwrap_or_else(|error| {
+        eprintln!("Spux Error - {error}");
+        process::exit(1);
+    })
+}
+
+// renders a loader declared with `spux_loader!`, from the markup and CSS
+// that `core::render` renders it into
+#[cfg(feature = "leptos")]
+pub fn render_loader(
+    definition: LoaderDefinition,
+    color: Option<&'static str>,
+    size: Size,
+    duration: Option<u32>,
+    label: &'static str,
+    renderer: Option<Renderer>,
+) -> impl IntoView {
+    // outside of an island the component never runs in the browser, so the
+    // loader is animated by CSS instead
+    #[cfg(feature = "web_animations")]
+    if let (Some(Renderer::WebAnimations(animation)), Some(svg), true) =
+        (renderer, definition.svg_loader(), is_interactive())
+    {
+        let color = resolve_color(color);
+        let size = resolve_size(size);
+        let duration = duration.unwrap_or(definition.default_duration());
+        let content = crate::web_animations::animated_svg(svg.clone(), animation, color, duration);
+        return view! {
+            <span class="spux-loader"
+                role="status"
+                aria-label=label
+                style=definition.root_style(color, size, duration, false)
+            >
+                {content}
+            </span>
+        }
+        .into_any();
+    }
+
+    // neither a nonce nor an external stylesheet allows style attributes, so
+    // the loader is then styled by classes instead (see the `csp` module)
+    let mut config = loader_config(definition, color, size, duration, label, renderer);
+    if cfg!(feature = "nonce") || use_context::<ExternalStylesheet>().is_some() {
+        config = config.class_styled();
+    }
+    let rendered = render_config(config);
+    let class = rendered
+        .attributes
+        .iter()
+        .find(|(name, _)| name == "class")
+        .map(|(_, class)| class.clone());
+    let style = (!rendered.style.is_empty()).then_some(rendered.style);
+
+    view! {
+        {loader_style(rendered.css)}
+        <span class=class
+            role="status"
+            aria-label=label
+            style=style
+            inner_html=rendered.content
+        ></span>
+    }
+    .into_any()
+}
+
+// the stylesheet of a component, which isn't rendered at all when the
+// components are styled by an external stylesheet (see the `csp` module)
+#[cfg(feature = "leptos")]
+pub(crate) fn loader_style(css: String) -> impl IntoView {
+    let external = use_context::<ExternalStylesheet>().is_some();
+    (!external).then(|| render_style(css))
+}
+
+// the stylesheet of a component, rendered right before it
+#[cfg(all(feature = "leptos", not(feature = "head_styles")))]
+fn render_style(css: String) -> impl IntoView {
+    view! { <style nonce=style_nonce()>{css}</style> }
+}
+
+// the nonce of the inline <style> elements, so that they are allowed by a
+// Content-Security-Policy (see the `csp` module)
+#[cfg(feature = "nonce")]
+pub fn style_nonce() -> Option<leptos::nonce::Nonce> {
+    leptos::nonce::use_nonce()
+}
+
+#[cfg(all(feature = "leptos", not(feature = "nonce")))]
+pub fn style_nonce() -> Option<String> {
+    None
+}
+
+#[cfg(feature = "head_styles")]
+use crate::head_styles::render_style;
+
+// whether a component runs in the browser, which in islands mode is only the
+// case inside of an island (see the `islands` module)
+#[cfg(all(
+    feature = "islands",
+    any(all(feature = "text", feature = "leptos"), feature = "web_animations")
+))]
+pub fn is_interactive() -> bool {
+    use leptos::reactive::owner::IsHydrating;
+
+    use_context::<IsHydrating>().is_some_and(|hydrating| hydrating.0)
+}
+
+#[cfg(all(
+    not(feature = "islands"),
+    any(all(feature = "text", feature = "leptos"), feature = "web_animations")
+))]
+pub fn is_interactive() -> bool {
+    true
+}
@@ -0,0 +1,40 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+mod linear;
+#[cfg(feature = "leptos")]
+pub use linear::LinearProgress;
+pub use linear::LinearProgressConfig;
+
+mod circular;
+#[cfg(feature = "leptos")]
+pub use circular::CircularProgress;
+pub use circular::CircularProgressConfig;
+
+// progress values are expected between 0.0 and 1.0, anything outside of
+// that range (including NaN) is clamped so that the bars never overflow
+fn clamp_progress(value: f64) -> f64 {
+    if value.is_nan() {
+        return 0.0;
+    }
+    value.clamp(0.0, 1.0)
+}
+
+// the value announced to assistive technologies, as a whole percent
+fn value_now(value: Option<f64>) -> Option<String> {
+    value.map(|value| (clamp_progress(value) * 100.0).round().to_string())
+}
+
+// the track and buffer are drawn with the same color at a lower opacity, by
+// appending an alpha channel to the #hex code or, for the color of the
+// surrounding text, by letting the browser mix it with transparent
+fn translucent(color: &str, percent: u32) -> String {
+    if color == "currentColor" {
+        return format!("color-mix(in srgb, currentColor {percent}%, transparent)");
+    }
+    format!("{color}{:02x}", percent * 255 / 100)
+}
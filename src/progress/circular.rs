@@ -0,0 +1,312 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+use super::{clamp_progress, translucent, value_now};
+use crate::core::{element, loader_color, Render, RenderedLoader};
+use crate::errors::ValidationError;
+#[cfg(feature = "leptos")]
+use crate::private::{loader_style, render_config};
+use crate::size::Size;
+#[cfg(feature = "leptos")]
+use leptos::prelude::*;
+use std::f64::consts::PI;
+
+const STROKE_WIDTH: u32 = 2;
+
+const STYLE: &str = "
+    .spux-circular-progress {
+        display: flex;
+        transform: rotate(-90deg);
+    }
+
+    .spux-circular-progress circle {
+        fill: transparent;
+        transition: stroke-dashoffset 400ms ease-in-out;
+    }
+
+    .spux-circular-progress-indeterminate {
+        animation: spux-circular-progress 1400ms linear forwards;
+        animation-iteration-count: infinite;
+    }
+
+    @keyframes spux-circular-progress {
+        0% {
+            transform: rotate(-90deg);
+        }
+        100% {
+            transform: rotate(270deg);
+        }
+    }
+";
+
+/// A circular progress ring, as rendered by the `CircularProgress`
+/// component, see the [`core`](crate::core) module.
+///
+/// Takes in a size (by px) and an optional color (#hex), which defaults to
+/// the color of the surrounding text. The ring is determinate once it is
+/// given a `value` (0.0 to 1.0), and the optional `buffer` renders a lighter
+/// ring behind it. The `label` names the ring for assistive technologies and
+/// defaults to "Loading".
+///
+/// # Example Usage
+/// ```
+/// use spux::core::render;
+/// use spux::progress::CircularProgressConfig;
+///
+/// let rendered = render(CircularProgressConfig::new(24).label("Uploading")).unwrap();
+/// assert!(rendered.html.contains("aria-label=\"Uploading\""));
+/// assert!(rendered.html.contains("stroke=\"currentColor\""));
+/// assert!(rendered.html.contains("spux-circular-progress-indeterminate"));
+/// assert!(rendered.html.contains("viewBox=\"0 0 24 24\""));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CircularProgressConfig {
+    color: Option<String>,
+    size: u32,
+    value: Option<f64>,
+    buffer: Option<f64>,
+    label: String,
+}
+
+impl CircularProgressConfig {
+    /// An indeterminate ring.
+    pub fn new(size: u32) -> Self {
+        CircularProgressConfig {
+            color: None,
+            size,
+            value: None,
+            buffer: None,
+            label: "Loading".to_string(),
+        }
+    }
+
+    pub fn color(self, color: impl Into<String>) -> Self {
+        CircularProgressConfig {
+            color: Some(color.into()),
+            ..self
+        }
+    }
+
+    pub fn label(self, label: impl Into<String>) -> Self {
+        CircularProgressConfig {
+            label: label.into(),
+            ..self
+        }
+    }
+
+    pub fn value(self, value: impl Into<Option<f64>>) -> Self {
+        CircularProgressConfig {
+            value: value.into(),
+            ..self
+        }
+    }
+
+    pub fn buffer(self, buffer: impl Into<Option<f64>>) -> Self {
+        CircularProgressConfig {
+            buffer: buffer.into(),
+            ..self
+        }
+    }
+
+    pub(crate) fn center(&self) -> f64 {
+        self.size as f64 / 2.0
+    }
+
+    pub(crate) fn radius(&self) -> f64 {
+        (self.size.saturating_sub(STROKE_WIDTH) as f64 / 2.0).max(0.0)
+    }
+
+    pub(crate) fn circumference(&self) -> f64 {
+        2.0 * PI * self.radius()
+    }
+
+    pub(crate) fn value_now(&self) -> Option<String> {
+        value_now(self.value)
+    }
+
+    pub(crate) fn class(&self) -> &'static str {
+        match self.value {
+            Some(_) => "spux-circular-progress",
+            None => "spux-circular-progress spux-circular-progress-indeterminate",
+        }
+    }
+
+    // without a value we show a quarter of the ring and let it spin
+    pub(crate) fn bar_offset(&self) -> String {
+        let offset = match self.value {
+            Some(value) => self.circumference() * (1.0 - clamp_progress(value)),
+            None => self.circumference() * 0.75,
+        };
+        offset.to_string()
+    }
+
+    pub(crate) fn buffer_offset(&self) -> String {
+        let offset = match (self.value, self.buffer) {
+            (Some(_), Some(buffer)) => self.circumference() * (1.0 - clamp_progress(buffer)),
+            _ => self.circumference(),
+        };
+        offset.to_string()
+    }
+
+    pub(crate) fn stroke_width(&self) -> u32 {
+        STROKE_WIDTH
+    }
+
+    // the track, buffer and bar are drawn with the same color, the track and
+    // buffer at a lower opacity. the track isn't dashed
+    pub(crate) fn rings(&self) -> [(String, Option<String>); 3] {
+        let color = self.color.as_deref().unwrap_or("currentColor");
+        [
+            (translucent(color, 20), None),
+            (translucent(color, 40), Some(self.buffer_offset())),
+            (color.to_string(), Some(self.bar_offset())),
+        ]
+    }
+
+    fn ring(&self, stroke: &str, offset: Option<&str>) -> String {
+        let center = self.center().to_string();
+        let radius = self.radius().to_string();
+        let stroke_width = self.stroke_width().to_string();
+        let circumference = self.circumference().to_string();
+        let mut attributes = vec![
+            ("cx", center.as_str()),
+            ("cy", &center),
+            ("r", &radius),
+            ("stroke", stroke),
+            ("stroke-width", &stroke_width),
+        ];
+        if let Some(offset) = offset {
+            attributes.extend([
+                ("stroke-dasharray", circumference.as_str()),
+                ("stroke-dashoffset", offset),
+            ]);
+        }
+        element("circle", &attributes, "")
+    }
+}
+
+impl Render for CircularProgressConfig {
+    fn render(self) -> Result<RenderedLoader, ValidationError> {
+        loader_color(self.color.as_deref())?;
+        Size::Px(self.size).validate()?;
+
+        let value_now = self.value_now();
+        let size = self.size.to_string();
+        let view_box = format!("0 0 {size} {size}");
+        let mut attributes = vec![
+            ("class", self.class()),
+            ("role", "progressbar"),
+            ("aria-label", &self.label),
+            ("aria-valuemin", "0"),
+            ("aria-valuemax", "100"),
+        ];
+        if let Some(value_now) = &value_now {
+            attributes.push(("aria-valuenow", value_now));
+        }
+        attributes.extend([
+            ("width", size.as_str()),
+            ("height", &size),
+            ("viewBox", &view_box),
+        ]);
+        let content = self
+            .rings()
+            .iter()
+            .map(|(stroke, offset)| self.ring(stroke, offset.as_deref()))
+            .collect();
+
+        Ok(RenderedLoader::new(
+            "svg",
+            &attributes,
+            String::new(),
+            content,
+            STYLE,
+            &["spux-circular-progress"],
+        ))
+    }
+}
+
+/// Renders a circular progress ring.
+///
+/// Takes in required props for size (by px) and an optional color (#hex),
+/// which defaults to the color of the surrounding text.
+/// The optional `value` (0.0 to 1.0) makes the ring determinate and animates
+/// smoothly whenever it changes, while the optional `buffer` renders a
+/// lighter ring behind it. When `value` is not given (or is `None`) the ring
+/// falls back to an indeterminate spin.
+///
+/// The ring is exposed to assistive technologies as an ARIA `progressbar`,
+/// named by `label`, which defaults to "Loading".
+///
+/// # Example Usage
+/// ```
+/// use leptos::prelude::*;
+/// use spux::progress::CircularProgress;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let value = RwSignal::new(Some(0.5));
+///
+///     view! {
+///         <CircularProgress color="#000000" size=24 value=value />
+///     }
+/// }
+/// ```
+#[cfg(feature = "leptos")]
+#[component]
+pub fn CircularProgress(
+    #[prop(default = None, into)] color: Option<&'static str>,
+    size: u32,
+    #[prop(optional, into)] value: MaybeProp<f64>,
+    #[prop(optional, into)] buffer: MaybeProp<f64>,
+    #[prop(default = "Loading")] label: &'static str,
+) -> impl IntoView {
+    let mut config = CircularProgressConfig::new(size).label(label);
+    if let Some(color) = color {
+        config = config.color(color);
+    }
+    let rendered = render_config(config.clone());
+
+    // the rings are kept (rather than rendered again) when the value
+    // changes, so that their offset transitions smoothly
+    let config = StoredValue::new(config);
+    let current =
+        move || config.with_value(|config| config.clone().value(value.get()).buffer(buffer.get()));
+    let rings = config.with_value(|config| config.rings());
+    let [track, buffer_ring, bar] = rings.map(|(stroke, _)| stroke);
+    let (center, radius, circumference) =
+        config.with_value(|config| (config.center(), config.radius(), config.circumference()));
+
+    view! {
+        {loader_style(rendered.css)}
+        <svg class=move || current().class()
+            role="progressbar"
+            aria-label=label
+            aria-valuemin="0"
+            aria-valuemax="100"
+            aria-valuenow=move || current().value_now()
+            width=size
+            height=size
+            viewBox=format!("0 0 {size} {size}")
+        >
+            <circle cx=center cy=center r=radius
+                stroke=track
+                stroke-width=STROKE_WIDTH
+            ></circle>
+            <circle cx=center cy=center r=radius
+                stroke=buffer_ring
+                stroke-width=STROKE_WIDTH
+                stroke-dasharray=circumference
+                stroke-dashoffset=move || current().buffer_offset()
+            ></circle>
+            <circle cx=center cy=center r=radius
+                stroke=bar
+                stroke-width=STROKE_WIDTH
+                stroke-dasharray=circumference
+                stroke-dashoffset=move || current().bar_offset()
+            ></circle>
+        </svg>
+    }
+}
@@ -0,0 +1,272 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+use super::{clamp_progress, translucent, value_now};
+use crate::core::{element, loader_color, Render, RenderedLoader};
+use crate::errors::ValidationError;
+#[cfg(feature = "leptos")]
+use crate::private::{loader_style, render_config};
+use crate::size::Size;
+#[cfg(feature = "leptos")]
+use leptos::prelude::*;
+
+const STYLE: &str = "
+    .spux-linear-progress {
+        position: relative;
+        display: flex;
+        width: 100%;
+        overflow: hidden;
+    }
+
+    .spux-linear-progress-buffer,
+    .spux-linear-progress-bar {
+        position: absolute;
+        top: 0px;
+        left: 0px;
+        height: 100%;
+        transition: width 400ms ease-in-out;
+    }
+
+    .spux-linear-progress-indeterminate {
+        animation: spux-linear-progress 1400ms ease-in-out forwards;
+        animation-iteration-count: infinite;
+    }
+
+    @keyframes spux-linear-progress {
+        0% {
+            transform: translateX(-100%);
+        }
+        100% {
+            transform: translateX(250%);
+        }
+    }
+";
+
+/// A horizontal progress bar, as rendered by the `LinearProgress` component,
+/// see the [`core`](crate::core) module.
+///
+/// Takes in a size (height by px) and an optional color (#hex), which
+/// defaults to the color of the surrounding text. The bar is determinate once
+/// it is given a `value` (0.0 to 1.0), and the optional `buffer` renders a
+/// lighter bar behind it. The `label` names the bar for assistive
+/// technologies and defaults to "Loading".
+///
+/// # Example Usage
+/// ```
+/// use spux::core::render;
+/// use spux::progress::LinearProgressConfig;
+///
+/// let rendered = render(LinearProgressConfig::new(4).color("#000000").value(0.25)).unwrap();
+/// assert!(rendered.html.contains("aria-label=\"Loading\""));
+/// assert!(rendered.html.contains("aria-valuenow=\"25\""));
+/// assert!(rendered.html.contains("width: 25%"));
+/// assert!(render(LinearProgressConfig::new(0)).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearProgressConfig {
+    color: Option<String>,
+    size: u32,
+    value: Option<f64>,
+    buffer: Option<f64>,
+    label: String,
+}
+
+impl LinearProgressConfig {
+    /// An indeterminate bar.
+    pub fn new(size: u32) -> Self {
+        LinearProgressConfig {
+            color: None,
+            size,
+            value: None,
+            buffer: None,
+            label: "Loading".to_string(),
+        }
+    }
+
+    pub fn color(self, color: impl Into<String>) -> Self {
+        LinearProgressConfig {
+            color: Some(color.into()),
+            ..self
+        }
+    }
+
+    pub fn label(self, label: impl Into<String>) -> Self {
+        LinearProgressConfig {
+            label: label.into(),
+            ..self
+        }
+    }
+
+    pub fn value(self, value: impl Into<Option<f64>>) -> Self {
+        LinearProgressConfig {
+            value: value.into(),
+            ..self
+        }
+    }
+
+    pub fn buffer(self, buffer: impl Into<Option<f64>>) -> Self {
+        LinearProgressConfig {
+            buffer: buffer.into(),
+            ..self
+        }
+    }
+
+    // the color of the bar, which the track and buffer are drawn with too
+    fn bar_color(&self) -> &str {
+        self.color.as_deref().unwrap_or("currentColor")
+    }
+
+    // the track is drawn with the same color at a lower opacity
+    fn style(&self) -> String {
+        format!(
+            "height: {}px; background-color: {}",
+            self.size,
+            translucent(self.bar_color(), 20)
+        )
+    }
+
+    pub(crate) fn value_now(&self) -> Option<String> {
+        value_now(self.value)
+    }
+
+    pub(crate) fn bar_class(&self) -> &'static str {
+        match self.value {
+            Some(_) => "spux-linear-progress-bar",
+            None => "spux-linear-progress-bar spux-linear-progress-indeterminate",
+        }
+    }
+
+    pub(crate) fn bar_style(&self) -> String {
+        let width = match self.value {
+            Some(value) => format!("{}%", clamp_progress(value) * 100.0),
+            None => "40%".to_string(),
+        };
+        format!("width: {width}; background-color: {}", self.bar_color())
+    }
+
+    pub(crate) fn buffer_style(&self) -> String {
+        let width = match (self.value, self.buffer) {
+            (Some(_), Some(buffer)) => format!("{}%", clamp_progress(buffer) * 100.0),
+            _ => "0%".to_string(),
+        };
+        format!(
+            "width: {width}; background-color: {}",
+            translucent(self.bar_color(), 40)
+        )
+    }
+}
+
+impl Render for LinearProgressConfig {
+    fn render(self) -> Result<RenderedLoader, ValidationError> {
+        loader_color(self.color.as_deref())?;
+        Size::Px(self.size).validate()?;
+
+        let value_now = self.value_now();
+        let mut attributes = vec![
+            ("class", "spux-linear-progress"),
+            ("role", "progressbar"),
+            ("aria-label", &self.label),
+            ("aria-valuemin", "0"),
+            ("aria-valuemax", "100"),
+        ];
+        if let Some(value_now) = &value_now {
+            attributes.push(("aria-valuenow", value_now));
+        }
+        let content = element(
+            "div",
+            &[
+                ("class", "spux-linear-progress-buffer"),
+                ("style", &self.buffer_style()),
+            ],
+            "",
+        ) + &element(
+            "div",
+            &[("class", self.bar_class()), ("style", &self.bar_style())],
+            "",
+        );
+
+        Ok(RenderedLoader::new(
+            "div",
+            &attributes,
+            self.style(),
+            content,
+            STYLE,
+            &[
+                "spux-linear-progress",
+                "spux-linear-progress-buffer",
+                "spux-linear-progress-bar",
+            ],
+        ))
+    }
+}
+
+/// Renders a horizontal progress bar that fills the width of its
+/// container.
+///
+/// Takes in required props for size (height by px) and an optional color (#hex),
+/// which defaults to the color of the surrounding text.
+/// The optional `value` (0.0 to 1.0) makes the bar determinate and animates
+/// smoothly whenever it changes, while the optional `buffer` renders a
+/// lighter bar behind it. When `value` is not given (or is `None`) the bar
+/// falls back to an indeterminate animation.
+///
+/// The bar is exposed to assistive technologies as an ARIA `progressbar`,
+/// named by `label`, which defaults to "Loading".
+///
+/// # Example Usage
+/// ```
+/// use leptos::prelude::*;
+/// use spux::progress::LinearProgress;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let value = RwSignal::new(0.25);
+///
+///     view! {
+///         <LinearProgress color="#000000" size=4 value=value />
+///     }
+/// }
+/// ```
+#[cfg(feature = "leptos")]
+#[component]
+pub fn LinearProgress(
+    #[prop(default = None, into)] color: Option<&'static str>,
+    size: u32,
+    #[prop(optional, into)] value: MaybeProp<f64>,
+    #[prop(optional, into)] buffer: MaybeProp<f64>,
+    #[prop(default = "Loading")] label: &'static str,
+) -> impl IntoView {
+    let mut config = LinearProgressConfig::new(size).label(label);
+    if let Some(color) = color {
+        config = config.color(color);
+    }
+    let rendered = render_config(config.clone());
+
+    // the bars are kept (rather than rendered again) when the value changes,
+    // so that their width transitions smoothly
+    let config = StoredValue::new(config);
+    let current =
+        move || config.with_value(|config| config.clone().value(value.get()).buffer(buffer.get()));
+
+    view! {
+        {loader_style(rendered.css)}
+        <div class="spux-linear-progress"
+            role="progressbar"
+            aria-label=label
+            aria-valuemin="0"
+            aria-valuemax="100"
+            aria-valuenow=move || current().value_now()
+            style=rendered.style
+        >
+            <div class="spux-linear-progress-buffer"
+                style=move || current().buffer_style()
+            ></div>
+            <div class=move || current().bar_class()
+                style=move || current().bar_style()
+            ></div>
+        </div>
+    }
+}
@@ -6,13 +6,54 @@
  */
 
 mod circle;
+#[cfg(feature = "leptos")]
 pub use circle::Circle;
+pub use circle::{circle_definition, circle_svg};
 
 mod filled_circle;
+#[cfg(feature = "leptos")]
 pub use filled_circle::FilledCircle;
+pub use filled_circle::{filled_circle_definition, filled_circle_svg};
 
 mod diamond;
+#[cfg(feature = "leptos")]
 pub use diamond::Diamond;
+pub use diamond::{diamond_definition, diamond_svg};
 
 mod filled_diamond;
+#[cfg(feature = "leptos")]
 pub use filled_diamond::FilledDiamond;
+pub use filled_diamond::{filled_diamond_definition, filled_diamond_svg};
+
+use crate::keyframes::{KeyframeStep, Keyframes, Length, Unit};
+use crate::shapes::Shape;
+
+// shrinks the loader to half of its size and back, offsetting it by a
+// fraction of its size to keep it centered while it shrinks
+fn pulse_keyframes(name: &str, offset_by: f64) -> Keyframes {
+    let full = |step: KeyframeStep| {
+        step.width(Length::spux_size())
+            .height(Length::spux_size())
+            .margin_top(0.px())
+            .margin_left(0.px())
+    };
+    let offset = Length::spux_size().divided(offset_by);
+    Keyframes::new(name)
+        .at(0.pct(), full)
+        .at(50.pct(), |step| {
+            step.width(Length::spux_size().divided(2.0))
+                .height(Length::spux_size().divided(2.0))
+                .margin_top(offset)
+                .margin_left(offset)
+        })
+        .at(100.pct(), full)
+}
+
+// the SVG loaders pulse by scaling instead, rotated like the shape they draw
+fn pulse_svg_keyframes(name: &str, shape: Shape) -> Keyframes {
+    let rotation = shape.rotation();
+    Keyframes::new(name)
+        .at(0.pct(), |step| step.rotate(rotation).scale(1.0))
+        .at(50.pct(), |step| step.rotate(rotation).scale(0.5))
+        .at(100.pct(), |step| step.rotate(rotation).scale(1.0))
+}
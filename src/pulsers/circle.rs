@@ -4,85 +4,60 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  */
-use crate::types::Validation;
-use leptos::prelude::*;
-use std::process;
+use super::{pulse_keyframes, pulse_svg_keyframes};
+use crate::shapes::Shape;
+use crate::spux_loader;
+use crate::svg::SvgLoader;
 
-/// Renders a circle and pulsates it immediately on
-/// the screen.
-///
-/// Takes in required props for both color (#hex) and size (by px)
-///
-/// # Example Usage
-/// ```
-/// use leptos::prelude::*;
-/// use spux::pulsers::Circle;
-///
-/// #[component]
-/// fn App() -> impl IntoView {
-///     view! {
-///         <Circle color="#000000" size=10 />
-///     }
-/// }
-/// ```
-
-#[component]
-pub fn Circle(color: &'static str, size: u32) -> impl IntoView {
-    // we intentionally create a compile time process exit to inform users
-    // that an incorrect #hex color code was used
-    let Ok(_) = color.validate_color_code() else {
-        eprintln!("Spux Error - Color should be in the #hex format (e.g #000000) for black");
-        process::exit(1);
-    };
-
-    let half_size = size / 2;
-    let quarter_size = size / 4;
-
-    let style = format!(
-        "
-        .spux-circle {{
-            background: transparent;
-            display:flex;
-            width: {size}px;
-            height: {size}px;
-            border-radius: 100%;
-            margin-top:0px;
-            margin-left:0px;
-            animation: spux-circle 1400ms ease-in-out forwards;
-            animation-iteration-count: infinite;
-        }}
-
-        @keyframes spux-circle {{
-
-            0% {{
-                width:{size}px;
-                height:{size}px;
+spux_loader! {
+    /// Renders a circle and pulsates it immediately on
+    /// the screen.
+    ///
+    /// Takes in required props for size (by px, or by em to render it inline with
+    /// the surrounding text) and an optional color (#hex), which defaults to the
+    /// color of the surrounding text. The optional duration (by ms) and label
+    /// change the speed of the animation and what is announced to assistive
+    /// technologies.
+    ///
+    /// # Example Usage
+    /// ```
+    /// use leptos::prelude::*;
+    /// use spux::pulsers::Circle;
+    ///
+    /// #[component]
+    /// fn App() -> impl IntoView {
+    ///     view! {
+    ///         <Circle color="#000000" size=10 />
+    ///     }
+    /// }
+    /// ```
+    pub Circle => circle_definition {
+        class: "spux-circle",
+        svg: circle_svg(),
+        glyphs: &["·", "•", "●", "•"],
+        style: "
+            .spux-circle {
+                background: transparent;
+                display:flex;
+                width: var(--spux-size);
+                height: var(--spux-size);
+                border: 2px solid var(--spux-color);
+                border-radius: 100%;
                 margin-top:0px;
                 margin-left:0px;
-            }}
-            50% {{
-                width:{half_size}px;
-                height:{half_size}px;
-                margin-top:{quarter_size}px;
-                margin-left:{quarter_size}px;
-            }}
-            100% {{
-                width:{size}px;
-                height:{size}px;
-                margin-top:0px;
-                margin-left:0px;
-            }}
-        }}  
-    "
-    );
-
-    view! {
-        <style>
-            {style}
-        </style>
-        <div class="spux-circle"
-            style:border="2px solid"
-            style:border-color=color
-        ></div>
+                animation: spux-circle var(--spux-duration) ease-in-out forwards;
+                animation-iteration-count: infinite;
+            }
+        ",
+        keyframes: pulse_keyframes("spux-circle", 4.0),
     }
 }
+
+/// The SVG drawing of the [`Circle`] loader, see the [`svg`](crate::svg) module.
+pub fn circle_svg() -> SvgLoader {
+    SvgLoader::new(
+        Shape::Circle.svg_shape(88.0),
+        pulse_svg_keyframes("spux-svg-circle", Shape::Circle),
+    )
+    .outline(6.0)
+}
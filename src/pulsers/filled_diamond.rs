@@ -4,83 +4,58 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  */
-use crate::types::Validation;
-use leptos::prelude::*;
-use std::process;
+use super::{pulse_keyframes, pulse_svg_keyframes};
+use crate::shapes::Shape;
+use crate::spux_loader;
+use crate::svg::SvgLoader;
 
-/// Renders a filled diamond and pulsates it immediately on
-/// the screen.
-///
-/// Takes in required props for both color (#hex) and size (by px)
-///
-/// # Example Usage
-/// ```
-/// use leptos::prelude::*;
-/// use spux::pulsers::FilledDiamond;
-///
-/// #[component]
-/// fn App() -> impl IntoView {
-///     view! {
-///         <FilledDiamond color="#000000" size=10 />
-///     }
-/// }
-/// ```
-
-#[component]
-pub fn FilledDiamond(color: &'static str, size: u32) -> impl IntoView {
-    // we intentionally create a compile time process exit to inform users
-    // that an incorrect #hex color code was used
-    let Ok(_) = color.validate_color_code() else {
-        eprintln!("Spux Error - Color should be in the #hex format (e.g #000000) for black");
-        process::exit(1);
-    };
-
-    let half_size = size / 2;
-    let margin_size = size / 8;
-
-    let style = format!(
-        "
-        .spux-filled-diamond {{
-            display:flex;
-            width: {size}px;
-            height: {size}px;
-            margin-top:0px;
-            margin-left:0px;
-            transform: rotate(45deg);
-            animation: spux-filled-diamond 1400ms ease-in-out forwards;
-            animation-iteration-count: infinite;
-        }}
-
-        @keyframes spux-filled-diamond  {{
-
-            0% {{
-                width:{size}px;
-                height:{size}px;
+spux_loader! {
+    /// Renders a filled diamond and pulsates it immediately on
+    /// the screen.
+    ///
+    /// Takes in required props for size (by px, or by em to render it inline with
+    /// the surrounding text) and an optional color (#hex), which defaults to the
+    /// color of the surrounding text. The optional duration (by ms) and label
+    /// change the speed of the animation and what is announced to assistive
+    /// technologies.
+    ///
+    /// # Example Usage
+    /// ```
+    /// use leptos::prelude::*;
+    /// use spux::pulsers::FilledDiamond;
+    ///
+    /// #[component]
+    /// fn App() -> impl IntoView {
+    ///     view! {
+    ///         <FilledDiamond color="#000000" size=10 />
+    ///     }
+    /// }
+    /// ```
+    pub FilledDiamond => filled_diamond_definition {
+        class: "spux-filled-diamond",
+        svg: filled_diamond_svg(),
+        glyphs: &["·", "⬩", "◆", "⬩"],
+        style: "
+            .spux-filled-diamond {
+                background: var(--spux-color);
+                display:flex;
+                width: var(--spux-size);
+                height: var(--spux-size);
+                transform: rotate(45deg);
                 margin-top:0px;
                 margin-left:0px;
-            }}
-            50% {{
-                width:{half_size}px;
-                height:{half_size}px;
-                margin-top:{margin_size}px;
-                margin-left:{margin_size}px;
-            }}
-            100% {{
-                width:{size}px;
-                height:{size}px;
-                margin-top:0px;
-                margin-left:0px;
-            }}
-        }}
-    "
-    );
-
-    view! {
-        <style>
-            {style}
-        </style>
-        <div class="spux-filled-diamond"
-            style:background-color=color
-        ></div>
+                animation: spux-filled-diamond var(--spux-duration) ease-in-out forwards;
+                animation-iteration-count: infinite;
+            }
+        ",
+        keyframes: pulse_keyframes("spux-filled-diamond", 8.0),
     }
 }
+
+/// The SVG drawing of the [`FilledDiamond`] loader, see the [`svg`](crate::svg) module.
+pub fn filled_diamond_svg() -> SvgLoader {
+    SvgLoader::new(
+        Shape::Diamond.svg_shape(66.0),
+        pulse_svg_keyframes("spux-svg-filled-diamond", Shape::Diamond),
+    )
+}
@@ -0,0 +1,389 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+/*!
+ Rendering loaders into animated images, without a browser or a GPU.
+
+ A [`Rasterizer`] samples the SVG drawing of a loader over one cycle of its
+ animation at a given frame rate, draws every frame in software and encodes
+ the frames into an animated GIF, APNG or WebP (e.g for emails, READMEs or chat
+ bots). It is plain Rust, so it can run in a build script or from the `spux`
+ binary.
+
+```
+use spux::keyframes::{Keyframes, Unit};
+use spux::raster::{ImageFormat, Rasterizer};
+use spux::svg::{SvgLoader, SvgShape};
+
+let spinner = SvgLoader::new(
+    SvgShape::Rect { x: 20.0, y: 20.0, width: 60.0, height: 60.0 },
+    Keyframes::new("brand-spinner")
+        .at(0.pct(), |step| step.rotate(0.deg()))
+        .at(100.pct(), |step| step.rotate(90.deg())),
+);
+
+let rasterizer = Rasterizer::new(spinner).color("#112233").size(32).fps(10);
+assert_eq!(rasterizer.frames().unwrap().len(), 14);
+
+let gif = rasterizer.encode(ImageFormat::Gif).unwrap();
+assert!(gif.starts_with(b"GIF89a"));
+assert!(rasterizer.encode(ImageFormat::Apng).unwrap().starts_with(b"\x89PNG"));
+assert!(rasterizer.clone().fps(0).frames().is_err());
+assert!(rasterizer.clone().fps(60).encode(ImageFormat::Gif).is_err());
+
+let webp = rasterizer.encode(ImageFormat::WebP).unwrap();
+assert!(webp.starts_with(b"RIFF"));
+assert_eq!(&webp[8..16], b"WEBPVP8X");
+```
+*/
+use crate::errors::ValidationError;
+use crate::keyframes::{Length, Transform};
+use crate::svg::{SvgLoader, SvgShape};
+use crate::types::rgb;
+use std::f32::consts::TAU;
+use tiny_skia::{FillRule, LineCap, LineJoin, Paint, PathBuilder, Pixmap, Rect, Stroke};
+
+/// The formats a loader can be encoded into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Gif,
+    Apng,
+    WebP,
+}
+
+/// Draws the frames of a loader, see the [module documentation](self).
+///
+/// The `color` (#hex) defaults to black, the `size` (by px) to 64, the
+/// `duration` (by ms) to 1400 and the `fps` to 30. Images are transparent
+/// unless a `background` (#hex) is given.
+///
+/// Only loaders with an SVG drawing can be rasterized, i.e the spinners and
+/// pulsers (e.g `spux::pulsers::circle_svg()`) and custom loaders given an
+/// `svg`. The other loaders (e.g grids, morphers, 3D and progress) are drawn
+/// by the browser from their CSS, which isn't available outside of one.
+///
+/// A `duration` or `fps` of 0 is rejected, and so is an `fps` above 50 for
+/// GIF images, whose frames can't be shorter than 1/50s.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rasterizer {
+    loader: SvgLoader,
+    color: String,
+    background: Option<String>,
+    size: u32,
+    duration: u32,
+    fps: u32,
+}
+
+impl Rasterizer {
+    pub fn new(loader: SvgLoader) -> Self {
+        Rasterizer {
+            loader,
+            color: "#000000".to_string(),
+            background: None,
+            size: 64,
+            duration: 1400,
+            fps: 30,
+        }
+    }
+
+    pub fn color(self, color: impl Into<String>) -> Self {
+        Rasterizer {
+            color: color.into(),
+            ..self
+        }
+    }
+
+    pub fn background(self, background: impl Into<String>) -> Self {
+        Rasterizer {
+            background: Some(background.into()),
+            ..self
+        }
+    }
+
+    pub fn size(self, size: u32) -> Self {
+        Rasterizer { size, ..self }
+    }
+
+    pub fn duration(self, duration: u32) -> Self {
+        Rasterizer { duration, ..self }
+    }
+
+    pub fn fps(self, fps: u32) -> Self {
+        Rasterizer { fps, ..self }
+    }
+
+    fn frame_count(&self) -> u32 {
+        ((self.duration as f64 * self.fps as f64 / 1000.0).round() as u32).max(1)
+    }
+
+    /// Draws every frame of one cycle of the animation, as (non premultiplied)
+    /// RGBA pixels.
+    pub fn frames(&self) -> Result<Vec<Vec<u8>>, ValidationError> {
+        if self.duration == 0 || self.fps == 0 {
+            return Err(ValidationError::new(
+                "Duration and fps should be larger than 0",
+            ));
+        }
+        let color = rgb(&self.color)?;
+        let background = self.background.as_deref().map(rgb).transpose()?;
+        if self.size == 0 || self.size > u16::MAX as u32 {
+            return Err(ValidationError::new(
+                "Size should be between 1px and 65535px",
+            ));
+        }
+
+        let count = self.frame_count();
+        Ok((0..count)
+            .map(|index| {
+                let mut pixmap = Pixmap::new(self.size, self.size)
+                    .expect("the size of the image was already validated");
+                if let Some((r, g, b)) = background {
+                    pixmap.fill(tiny_skia::Color::from_rgba8(r, g, b, 255));
+                }
+                self.draw(&mut pixmap, color, index as f64 / count as f64);
+                pixmap.take_demultiplied()
+            })
+            .collect())
+    }
+
+    /// Draws and encodes one cycle of the animation, which loops forever.
+    pub fn encode(&self, format: ImageFormat) -> Result<Vec<u8>, ValidationError> {
+        // browsers play gif frames shorter than 2/100s at 1/10s, which would
+        // slow the loader down
+        if format == ImageFormat::Gif && self.fps > 50 {
+            return Err(ValidationError::new(
+                "GIF images should have at most 50 fps",
+            ));
+        }
+        let frames = self.frames()?;
+        let encoding_error = |error: &dyn std::error::Error| {
+            ValidationError::new(&format!("Couldn't encode the image ({error})"))
+        };
+        match format {
+            ImageFormat::Gif => self.gif(frames).map_err(|error| encoding_error(&error)),
+            ImageFormat::Apng => self.apng(frames).map_err(|error| encoding_error(&error)),
+            ImageFormat::WebP => self.webp(frames).map_err(|error| encoding_error(&error)),
+        }
+    }
+
+    fn gif(&self, frames: Vec<Vec<u8>>) -> Result<Vec<u8>, gif::EncodingError> {
+        let size = self.size as u16;
+        let count = frames.len() as f64;
+        let mut image = Vec::new();
+        {
+            let mut encoder = gif::Encoder::new(&mut image, size, size, &[])?;
+            encoder.set_repeat(gif::Repeat::Infinite)?;
+            // gifs are timed by 1/100s, so the rounding of every frame is
+            // spread out to keep the total duration of the cycle exact (short
+            // of frames that would round below 2/100s, see `encode`)
+            let centiseconds = |index: f64| (index * self.duration as f64 / 10.0 / count).round();
+            for (index, mut pixels) in frames.into_iter().enumerate() {
+                let mut frame = gif::Frame::from_rgba_speed(size, size, &mut pixels, 10);
+                let delay = centiseconds(index as f64 + 1.0) - centiseconds(index as f64);
+                frame.delay = delay.clamp(2.0, u16::MAX as f64) as u16;
+                frame.dispose = gif::DisposalMethod::Background;
+                encoder.write_frame(&frame)?;
+            }
+        }
+        Ok(image)
+    }
+
+    fn apng(&self, frames: Vec<Vec<u8>>) -> Result<Vec<u8>, png::EncodingError> {
+        let mut image = Vec::new();
+        {
+            let mut encoder = png::Encoder::new(&mut image, self.size, self.size);
+            encoder.set_color(png::ColorType::Rgba);
+            encoder.set_depth(png::BitDepth::Eight);
+            encoder.set_animated(frames.len() as u32, 0)?;
+            let (numerator, denominator) = frame_delay(self.duration, frames.len() as u32);
+            encoder.set_frame_delay(numerator, denominator)?;
+            encoder.set_dispose_op(png::DisposeOp::Background)?;
+            encoder.set_blend_op(png::BlendOp::Source)?;
+            let mut writer = encoder.write_header()?;
+            for pixels in frames {
+                writer.write_image_data(&pixels)?;
+            }
+            writer.finish()?;
+        }
+        Ok(image)
+    }
+
+    // every frame is encoded as a lossless still image, whose VP8L chunk is
+    // wrapped into an ANMF chunk of an animated WebP
+    fn webp(&self, frames: Vec<Vec<u8>>) -> Result<Vec<u8>, image_webp::EncodingError> {
+        let size = self.size;
+        let count = frames.len() as f64;
+        let milliseconds = |index: f64| (index * self.duration as f64 / count).round() as u32;
+
+        // the canvas has an alpha channel and is animated, with a transparent
+        // background and looping forever
+        let mut chunks = riff_chunk(
+            b"VP8X",
+            &[&[0b0001_0010, 0, 0, 0], &u24(size - 1), &u24(size - 1)],
+        );
+        chunks.extend(riff_chunk(b"ANIM", &[&[0; 4], &[0; 2]]));
+        for (index, pixels) in frames.into_iter().enumerate() {
+            let mut image = Vec::new();
+            image_webp::WebPEncoder::new(&mut image).encode(
+                &pixels,
+                size,
+                size,
+                image_webp::ColorType::Rgba8,
+            )?;
+            let duration = milliseconds(index as f64 + 1.0) - milliseconds(index as f64);
+            // the frame covers the canvas from its top left corner, replaces
+            // the previous frame rather than being blended over it, and is
+            // disposed of to the background
+            chunks.extend(riff_chunk(
+                b"ANMF",
+                &[
+                    &u24(0),
+                    &u24(0),
+                    &u24(size - 1),
+                    &u24(size - 1),
+                    &u24(duration),
+                    &[0b0000_0011],
+                    // the VP8L chunk, past the RIFF header of the still image
+                    &image[12..],
+                ],
+            ));
+        }
+
+        let mut image = b"RIFF".to_vec();
+        image.extend((chunks.len() as u32 + 4).to_le_bytes());
+        image.extend(b"WEBP");
+        image.extend(chunks);
+        Ok(image)
+    }
+
+    // draws the loader the same way the browser draws its SVG, with dashes
+    // only being drawn on circles (as they are by the built-in loaders)
+    fn draw(&self, pixmap: &mut Pixmap, (r, g, b): (u8, u8, u8), progress: f64) {
+        let frame = self.loader.frame(progress);
+        let (x, y) = self.loader.shape().center();
+        let (x, y) = (x as f32, y as f32);
+
+        let scale = self.size as f32 / 100.0;
+        let mut transform = tiny_skia::Transform::from_scale(scale, scale).pre_translate(x, y);
+        for step in &frame.transforms {
+            transform = match step {
+                Transform::Rotate(angle) => transform.pre_rotate(angle.0 as f32),
+                Transform::Scale(by) => transform.pre_scale(*by as f32, *by as f32),
+                Transform::TranslateX(Length::Px(length)) => {
+                    transform.pre_translate(*length as f32, 0.0)
+                }
+                Transform::TranslateY(Length::Px(length)) => {
+                    transform.pre_translate(0.0, *length as f32)
+                }
+                _ => transform,
+            };
+        }
+        let transform = transform.pre_translate(-x, -y);
+
+        let path = match self.loader.shape() {
+            SvgShape::Rect {
+                x,
+                y,
+                width,
+                height,
+            } => Rect::from_xywh(*x as f32, *y as f32, *width as f32, *height as f32)
+                .map(PathBuilder::from_rect),
+            SvgShape::Circle { cx, cy, r } => match self.loader.visible_stroke() {
+                Some(visible) => {
+                    let (cx, cy, r) = (*cx as f32, *cy as f32, *r as f32);
+                    let sweep = TAU * visible as f32 / 100.0;
+                    let segments = 96;
+                    let mut builder = PathBuilder::new();
+                    builder.move_to(cx + r, cy);
+                    for segment in 1..=segments {
+                        let angle = sweep * segment as f32 / segments as f32;
+                        builder.line_to(cx + r * angle.cos(), cy + r * angle.sin());
+                    }
+                    builder.finish()
+                }
+                None => PathBuilder::from_circle(*cx as f32, *cy as f32, *r as f32),
+            },
+            SvgShape::Polygon(points) => {
+                let mut builder = PathBuilder::new();
+                for (index, (x, y)) in points.iter().enumerate() {
+                    if index == 0 {
+                        builder.move_to(*x as f32, *y as f32);
+                    } else {
+                        builder.line_to(*x as f32, *y as f32);
+                    }
+                }
+                builder.close();
+                builder.finish()
+            }
+        };
+        let Some(path) = path else {
+            return;
+        };
+
+        let mut paint = Paint {
+            anti_alias: true,
+            ..Paint::default()
+        };
+        paint.set_color_rgba8(
+            r,
+            g,
+            b,
+            (frame.opacity.clamp(0.0, 1.0) * 255.0).round() as u8,
+        );
+
+        if self.loader.is_filled() {
+            pixmap.fill_path(&path, &paint, FillRule::Winding, transform, None);
+        }
+        if let Some(width) = self.loader.stroke_width() {
+            let stroke = Stroke {
+                width: width as f32,
+                line_cap: LineCap::Round,
+                line_join: LineJoin::Round,
+                ..Stroke::default()
+            };
+            pixmap.stroke_path(&path, &paint, &stroke, transform, None);
+        }
+    }
+}
+
+// the delay of every frame of an APNG, which is `duration / count` ms as
+// a fraction of seconds. Fractions that don't fit into 16 bits are halved
+// until they do, which keeps them within a fraction of a percent
+fn frame_delay(duration: u32, count: u32) -> (u16, u16) {
+    let gcd = |mut a: u64, mut b: u64| {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    };
+    let (numerator, denominator) = (u64::from(duration), u64::from(count) * 1000);
+    let divisor = gcd(numerator, denominator);
+    let (mut numerator, mut denominator) = (numerator / divisor, denominator / divisor);
+    while numerator > u64::from(u16::MAX) || denominator > u64::from(u16::MAX) {
+        numerator = numerator.div_ceil(2);
+        denominator = denominator.div_ceil(2);
+    }
+    (numerator as u16, denominator as u16)
+}
+
+// a chunk of a RIFF container (e.g WebP), padded to an even length
+fn riff_chunk(name: &[u8; 4], parts: &[&[u8]]) -> Vec<u8> {
+    let data = parts.concat();
+    let mut chunk = name.to_vec();
+    chunk.extend((data.len() as u32).to_le_bytes());
+    chunk.extend(&data);
+    if data.len() % 2 == 1 {
+        chunk.push(0);
+    }
+    chunk
+}
+
+// the 24 bit (little endian) integers of WebP chunks
+fn u24(value: u32) -> [u8; 3] {
+    let [a, b, c, _] = value.to_le_bytes();
+    [a, b, c]
+}
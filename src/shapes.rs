@@ -0,0 +1,109 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use crate::keyframes::{Angle, Percent};
+use crate::svg::SvgShape;
+
+/// The basic shapes that Spux loaders are drawn from.
+///
+/// Every shape is described within the same square box (i.e `size` by `size`)
+/// by a border radius, a 4 point clip path and a rotation. Keeping the same
+/// number of points for every shape lets the browser interpolate between them.
+/// The morphers animate this description directly, while the SVG drawings of
+/// the spinners and pulsers are derived from it by [`svg_shape`](Self::svg_shape).
+///
+/// # Example Usage
+/// ```
+/// use spux::shapes::Shape;
+/// use spux::svg::SvgShape;
+///
+/// assert_eq!(
+///     Shape::Square.svg_shape(66.0),
+///     SvgShape::Rect { x: 17.0, y: 17.0, width: 66.0, height: 66.0 },
+/// );
+/// assert_eq!(
+///     Shape::Circle.svg_shape(88.0),
+///     SvgShape::Circle { cx: 50.0, cy: 50.0, r: 44.0 },
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Shape {
+    Square,
+    Circle,
+    Diamond,
+    Triangle,
+}
+
+impl Shape {
+    /// The lowercase name of the shape, as used in class and keyframe names.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Shape::Square => "square",
+            Shape::Circle => "circle",
+            Shape::Diamond => "diamond",
+            Shape::Triangle => "triangle",
+        }
+    }
+
+    /// The border radius that rounds the box into this shape.
+    pub fn border_radius(&self) -> Percent {
+        match self {
+            Shape::Circle => Percent(50.0),
+            Shape::Square | Shape::Diamond | Shape::Triangle => Percent(0.0),
+        }
+    }
+
+    /// The 4 point polygon (as percentages of the box) that clips the box
+    /// into this shape.
+    pub fn clip_path(&self) -> [(f64, f64); 4] {
+        match self {
+            // an (almost) equilateral triangle, centered within the box
+            Shape::Triangle => [(50.0, 6.0), (50.0, 6.0), (100.0, 93.0), (0.0, 93.0)],
+            Shape::Square | Shape::Circle | Shape::Diamond => {
+                [(0.0, 0.0), (100.0, 0.0), (100.0, 100.0), (0.0, 100.0)]
+            }
+        }
+    }
+
+    /// The rotation applied to the box for this shape.
+    pub fn rotation(&self) -> Angle {
+        match self {
+            Shape::Diamond => Angle(45.0),
+            Shape::Square | Shape::Circle | Shape::Triangle => Angle(0.0),
+        }
+    }
+
+    /// The shape as drawn by an SVG loader, filling a box of `size` at the
+    /// center of the `0 0 100 100` viewBox. The rotation is left to the
+    /// keyframes of the loader.
+    pub fn svg_shape(&self, size: f64) -> SvgShape {
+        let (min, max) = (50.0 - size / 2.0, 50.0 + size / 2.0);
+        // percentages of the box to coordinates of the viewBox, rounded so
+        // that the markup stays readable
+        let scale = |percent: f64| ((min + percent / 100.0 * size) * 100.0).round() / 100.0;
+
+        let mut points = self.clip_path().map(|(x, y)| (scale(x), scale(y))).to_vec();
+        points.dedup();
+
+        if self.border_radius() == Percent(50.0) {
+            SvgShape::Circle {
+                cx: 50.0,
+                cy: 50.0,
+                r: size / 2.0,
+            }
+        } else if points == [(min, min), (max, min), (max, max), (min, max)] {
+            SvgShape::Rect {
+                x: min,
+                y: min,
+                width: size,
+                height: size,
+            }
+        } else {
+            SvgShape::Polygon(points)
+        }
+    }
+}
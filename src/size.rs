@@ -0,0 +1,127 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+use crate::errors::ValidationError;
+use std::fmt;
+use std::str::FromStr;
+
+/// The size of a Spux loader.
+///
+/// A plain number (e.g `size=10`) is converted into `Size::Px`, which renders
+/// the loader as a block of a fixed size. `Size::Em` renders the loader inline
+/// instead, sized relative to the font of the surrounding text and aligned
+/// with it, so that it can be placed inside of a sentence or a button. Sizes
+/// that can't be rendered (e.g 0px, -2em or NaNem) are rejected.
+///
+/// # Example Usage
+/// ```
+/// use spux::size::Size;
+///
+/// let size: Size = 10.into();
+/// assert_eq!(size.to_string(), "10px");
+/// assert_eq!(Size::Em(1.0).divided(2).to_string(), "0.5em");
+///
+/// assert_eq!("1.5em".parse::<Size>().unwrap(), Size::Em(1.5));
+/// assert!("NaNem".parse::<Size>().is_err());
+/// assert!("0".parse::<Size>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Size {
+    /// A fixed size by px.
+    Px(u32),
+    /// A size relative to the font size of the surrounding text.
+    Em(f64),
+}
+
+impl Size {
+    /// Divides the size, rounding down whole pixels the same way as the
+    /// loaders always have.
+    pub fn divided(&self, by: u32) -> Size {
+        match self {
+            Size::Px(size) => Size::Px(size / by.max(1)),
+            Size::Em(size) => Size::Em(size / by.max(1) as f64),
+        }
+    }
+
+    /// Multiplies the size.
+    pub fn scaled(&self, by: u32) -> Size {
+        match self {
+            Size::Px(size) => Size::Px(size * by),
+            Size::Em(size) => Size::Em(size * by as f64),
+        }
+    }
+
+    // sizes that can't be rendered (e.g 0px, -2em or NaNem) are rejected
+    // rather than written into the CSS of the loader
+    pub(crate) fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            Size::Px(size) if *size > 0 => Ok(()),
+            Size::Em(size) if size.is_finite() && *size > 0.0 => Ok(()),
+            _ => Err(ValidationError::new(
+                "Size should be larger than 0 by px (e.g 24) or by em (e.g Size::Em(1.5))",
+            )),
+        }
+    }
+
+    /// Whether the loader is rendered inline with the surrounding text.
+    pub fn is_inline(&self) -> bool {
+        matches!(self, Size::Em(_))
+    }
+
+    /// The display of the outermost element of a loader.
+    pub fn display(&self) -> &'static str {
+        match self {
+            Size::Px(_) => "flex",
+            Size::Em(_) => "inline-flex",
+        }
+    }
+
+    /// The vertical alignment of the outermost element of a loader. Inline
+    /// loaders are aligned with the top of the surrounding text, so that a
+    /// 1em loader lines up with the capital letters next to it.
+    pub fn vertical_align(&self) -> &'static str {
+        match self {
+            Size::Px(_) => "baseline",
+            Size::Em(_) => "text-top",
+        }
+    }
+}
+
+impl From<u32> for Size {
+    fn from(size: u32) -> Self {
+        Size::Px(size)
+    }
+}
+
+impl fmt::Display for Size {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Size::Px(size) => write!(f, "{size}px"),
+            Size::Em(size) => write!(f, "{size}em"),
+        }
+    }
+}
+
+impl FromStr for Size {
+    type Err = ValidationError;
+
+    /// Parses a size by px (e.g `24` or `24px`) or by em (e.g `1.5em`).
+    fn from_str(size: &str) -> Result<Self, Self::Err> {
+        let invalid =
+            || ValidationError::new("Size should be by px (e.g 24px) or by em (e.g 1.5em)");
+        let size = match size.strip_suffix("em") {
+            Some(em) => em.parse().map(Size::Em).map_err(|_| invalid())?,
+            None => size
+                .strip_suffix("px")
+                .unwrap_or(size)
+                .parse()
+                .map(Size::Px)
+                .map_err(|_| invalid())?,
+        };
+        size.validate()?;
+        Ok(size)
+    }
+}
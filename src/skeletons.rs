@@ -0,0 +1,130 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+use crate::errors::ValidationError;
+use crate::types::{Shading, Validation};
+
+mod text;
+#[cfg(feature = "leptos")]
+pub use text::SkeletonText;
+pub use text::SkeletonTextConfig;
+
+mod avatar;
+#[cfg(feature = "leptos")]
+pub use avatar::SkeletonAvatar;
+pub use avatar::SkeletonAvatarConfig;
+
+mod rect;
+#[cfg(feature = "leptos")]
+pub use rect::SkeletonRect;
+pub use rect::SkeletonRectConfig;
+
+mod card;
+#[cfg(feature = "leptos")]
+pub use card::SkeletonCard;
+pub use card::SkeletonCardConfig;
+
+mod table;
+#[cfg(feature = "leptos")]
+pub use table::SkeletonTable;
+pub use table::SkeletonTableConfig;
+
+/// How a skeleton placeholder is animated.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SkeletonAnimation {
+    /// A highlight sweeps across the placeholders. The sweep is anchored to the
+    /// viewport so that every skeleton on the page shares the same phase.
+    #[default]
+    Shimmer,
+    /// The placeholders fade in and out together.
+    Pulse,
+}
+
+impl SkeletonAnimation {
+    fn class(&self) -> &'static str {
+        match self {
+            SkeletonAnimation::Shimmer => "spux-skeleton spux-skeleton-shimmer",
+            SkeletonAnimation::Pulse => "spux-skeleton spux-skeleton-pulse",
+        }
+    }
+
+    fn class_names(&self) -> [&'static str; 2] {
+        match self {
+            SkeletonAnimation::Shimmer => ["spux-skeleton", "spux-skeleton-shimmer"],
+            SkeletonAnimation::Pulse => ["spux-skeleton", "spux-skeleton-pulse"],
+        }
+    }
+}
+
+// every skeleton shares the same stylesheet, the colors are handed down through
+// custom properties so that differently colored skeletons don't override each other
+const SKELETON_STYLE: &str = "
+    .spux-skeleton {
+        display: block;
+        background-color: var(--spux-skeleton-base);
+    }
+
+    .spux-skeleton-shimmer {
+        background-image: linear-gradient(
+            90deg,
+            var(--spux-skeleton-base) 0%,
+            var(--spux-skeleton-highlight) 50%,
+            var(--spux-skeleton-base) 100%
+        );
+        background-size: 200vw 100%;
+        background-attachment: fixed;
+        animation: spux-skeleton-shimmer 1400ms linear forwards;
+        animation-iteration-count: infinite;
+    }
+
+    .spux-skeleton-pulse {
+        animation: spux-skeleton-pulse 1400ms ease-in-out forwards;
+        animation-iteration-count: infinite;
+    }
+
+    @keyframes spux-skeleton-shimmer {
+        0% {
+            background-position: 100vw 0px;
+        }
+        100% {
+            background-position: -100vw 0px;
+        }
+    }
+
+    @keyframes spux-skeleton-pulse {
+        0% {
+            opacity: 1;
+        }
+        50% {
+            opacity: 0.4;
+        }
+        100% {
+            opacity: 1;
+        }
+    }
+";
+
+// validates the base and (optional) highlight colors, and derives the highlight
+// from the base color when none was given
+fn skeleton_colors(color: &str, highlight: Option<&str>) -> Result<String, ValidationError> {
+    color.validate_color_code()?;
+
+    let highlight = match highlight {
+        Some(highlight) => {
+            highlight.validate_color_code().map_err(|_| {
+                ValidationError::new(
+                    "Highlight should be in the #hex format (e.g #000000) for black",
+                )
+            })?;
+            highlight.to_string()
+        }
+        None => color.lighten(0.5),
+    };
+
+    Ok(format!(
+        "--spux-skeleton-base: {color}; --spux-skeleton-highlight: {highlight};"
+    ))
+}
@@ -0,0 +1,119 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+use super::{skeleton_colors, SkeletonAnimation, SKELETON_STYLE};
+use crate::core::{Render, RenderedLoader};
+use crate::errors::ValidationError;
+#[cfg(feature = "leptos")]
+use crate::private::{loader_style, render_config};
+use crate::size::Size;
+#[cfg(feature = "leptos")]
+use leptos::prelude::*;
+
+/// A circular placeholder, as rendered by the `SkeletonAvatar` component,
+/// see the [`core`](crate::core) module.
+///
+/// Takes in a color (#hex) and a size (by px). The highlight color defaults
+/// to a lighter shade of `color`.
+///
+/// # Example Usage
+/// ```
+/// use spux::core::render;
+/// use spux::skeletons::{SkeletonAnimation, SkeletonAvatarConfig};
+///
+/// let avatar = SkeletonAvatarConfig::new("#e0e0e0", 40).animation(SkeletonAnimation::Pulse);
+/// let rendered = render(avatar).unwrap();
+/// assert!(rendered.html.contains("spux-skeleton-pulse"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SkeletonAvatarConfig {
+    color: String,
+    size: u32,
+    highlight: Option<String>,
+    animation: SkeletonAnimation,
+}
+
+impl SkeletonAvatarConfig {
+    pub fn new(color: impl Into<String>, size: u32) -> Self {
+        SkeletonAvatarConfig {
+            color: color.into(),
+            size,
+            highlight: None,
+            animation: SkeletonAnimation::default(),
+        }
+    }
+
+    pub fn highlight(self, highlight: impl Into<String>) -> Self {
+        SkeletonAvatarConfig {
+            highlight: Some(highlight.into()),
+            ..self
+        }
+    }
+
+    pub fn animation(self, animation: SkeletonAnimation) -> Self {
+        SkeletonAvatarConfig { animation, ..self }
+    }
+}
+
+impl Render for SkeletonAvatarConfig {
+    fn render(self) -> Result<RenderedLoader, ValidationError> {
+        let colors = skeleton_colors(&self.color, self.highlight.as_deref())?;
+        Size::Px(self.size).validate()?;
+        let style = format!(
+            "{colors} flex-shrink: 0; width: {size}px; height: {size}px; border-radius: 100%",
+            size = self.size
+        );
+
+        Ok(RenderedLoader::new(
+            "div",
+            &[("class", self.animation.class()), ("aria-hidden", "true")],
+            style,
+            String::new(),
+            SKELETON_STYLE,
+            &self.animation.class_names(),
+        ))
+    }
+}
+
+/// Renders a circular placeholder for avatars and icons.
+///
+/// Takes in required props for both color (#hex) and size (by px). The
+/// highlight color defaults to a lighter shade of `color`.
+///
+/// # Example Usage
+/// ```
+/// use leptos::prelude::*;
+/// use spux::skeletons::SkeletonAvatar;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <SkeletonAvatar color="#e0e0e0" size=40 />
+///     }
+/// }
+/// ```
+#[cfg(feature = "leptos")]
+#[component]
+pub fn SkeletonAvatar(
+    color: &'static str,
+    size: u32,
+    #[prop(default = None, into)] highlight: Option<&'static str>,
+    #[prop(optional)] animation: SkeletonAnimation,
+) -> impl IntoView {
+    let mut config = SkeletonAvatarConfig::new(color, size).animation(animation);
+    if let Some(highlight) = highlight {
+        config = config.highlight(highlight);
+    }
+    let rendered = render_config(config);
+
+    view! {
+        {loader_style(rendered.css)}
+        <div class=animation.class()
+            aria-hidden="true"
+            style=rendered.style
+        ></div>
+    }
+}
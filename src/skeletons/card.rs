@@ -0,0 +1,147 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+use super::{
+    SkeletonAnimation, SkeletonAvatarConfig, SkeletonRectConfig, SkeletonTextConfig, SKELETON_STYLE,
+};
+use crate::core::{element, Render, RenderedLoader};
+use crate::errors::ValidationError;
+#[cfg(feature = "leptos")]
+use crate::private::{loader_style, render_config};
+#[cfg(feature = "leptos")]
+use leptos::prelude::*;
+
+/// A card shaped placeholder, as rendered by the `SkeletonCard` component,
+/// see the [`core`](crate::core) module.
+///
+/// Takes in a color (#hex) and a size (line height by px), which every other
+/// part of the card is scaled from. The highlight color defaults to a lighter
+/// shade of `color`.
+///
+/// # Example Usage
+/// ```
+/// use spux::core::render;
+/// use spux::skeletons::SkeletonCardConfig;
+///
+/// let rendered = render(SkeletonCardConfig::new("#e0e0e0", 12)).unwrap();
+/// assert!(rendered.html.contains("height: 120px"));
+/// assert!(rendered.html.contains("border-radius: 100%"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SkeletonCardConfig {
+    color: String,
+    size: u32,
+    highlight: Option<String>,
+    animation: SkeletonAnimation,
+}
+
+impl SkeletonCardConfig {
+    pub fn new(color: impl Into<String>, size: u32) -> Self {
+        SkeletonCardConfig {
+            color: color.into(),
+            size,
+            highlight: None,
+            animation: SkeletonAnimation::default(),
+        }
+    }
+
+    pub fn highlight(self, highlight: impl Into<String>) -> Self {
+        SkeletonCardConfig {
+            highlight: Some(highlight.into()),
+            ..self
+        }
+    }
+
+    pub fn animation(self, animation: SkeletonAnimation) -> Self {
+        SkeletonCardConfig { animation, ..self }
+    }
+}
+
+impl Render for SkeletonCardConfig {
+    fn render(self) -> Result<RenderedLoader, ValidationError> {
+        let SkeletonCardConfig {
+            color,
+            size,
+            highlight,
+            animation,
+        } = self;
+
+        // the media area, the avatar and the lines are shaded the same way
+        let mut media = SkeletonRectConfig::new(&color, size * 10).animation(animation);
+        let mut avatar = SkeletonAvatarConfig::new(&color, size * 3).animation(animation);
+        let mut text = SkeletonTextConfig::new(&color, size)
+            .lines(2)
+            .animation(animation);
+        if let Some(highlight) = highlight {
+            media = media.highlight(&highlight);
+            avatar = avatar.highlight(&highlight);
+            text = text.highlight(highlight);
+        }
+
+        let content = media.render()?.html
+            + &element(
+                "div",
+                &[(
+                    "style",
+                    &format!("display: flex; align-items: center; gap: {size}px"),
+                )],
+                &(avatar.render()?.html + &text.render()?.html),
+            );
+        let style = format!("width: 100%; display: flex; flex-direction: column; gap: {size}px");
+
+        Ok(RenderedLoader::new(
+            "div",
+            &[("aria-hidden", "true")],
+            style,
+            content,
+            SKELETON_STYLE,
+            &animation.class_names(),
+        ))
+    }
+}
+
+/// Renders a card shaped placeholder with a media area, an avatar and a
+/// few lines of text.
+///
+/// Takes in required props for both color (#hex) and size (line height by px),
+/// every other part of the card is scaled from `size`. The highlight color
+/// defaults to a lighter shade of `color`.
+///
+/// # Example Usage
+/// ```
+/// use leptos::prelude::*;
+/// use spux::skeletons::SkeletonCard;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <SkeletonCard color="#e0e0e0" size=12 />
+///     }
+/// }
+/// ```
+#[cfg(feature = "leptos")]
+#[component]
+pub fn SkeletonCard(
+    color: &'static str,
+    size: u32,
+    #[prop(default = None, into)] highlight: Option<&'static str>,
+    #[prop(optional)] animation: SkeletonAnimation,
+) -> impl IntoView {
+    let mut config = SkeletonCardConfig::new(color, size).animation(animation);
+    if let Some(highlight) = highlight {
+        config = config.highlight(highlight);
+    }
+    let rendered = render_config(config);
+
+    view! {
+        {loader_style(rendered.css)}
+        <div
+            aria-hidden="true"
+            style=rendered.style
+            inner_html=rendered.content
+        ></div>
+    }
+}
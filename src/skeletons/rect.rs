@@ -0,0 +1,140 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+use super::{skeleton_colors, SkeletonAnimation, SKELETON_STYLE};
+use crate::core::{Render, RenderedLoader};
+use crate::errors::ValidationError;
+#[cfg(feature = "leptos")]
+use crate::private::{loader_style, render_config};
+use crate::size::Size;
+#[cfg(feature = "leptos")]
+use leptos::prelude::*;
+
+/// A rectangular placeholder, as rendered by the `SkeletonRect` component,
+/// see the [`core`](crate::core) module.
+///
+/// Takes in a color (#hex) and a height (by px). The rectangle fills its
+/// container unless it is given a `width` (by px), and the highlight color
+/// defaults to a lighter shade of `color`.
+///
+/// # Example Usage
+/// ```
+/// use spux::core::render;
+/// use spux::skeletons::SkeletonRectConfig;
+///
+/// let rendered = render(SkeletonRectConfig::new("#e0e0e0", 120).width(80)).unwrap();
+/// assert!(rendered.html.contains("width: 80px; height: 120px"));
+/// assert!(render(SkeletonRectConfig::new("#e0e0e0", 120).width(0)).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SkeletonRectConfig {
+    color: String,
+    height: u32,
+    width: Option<u32>,
+    highlight: Option<String>,
+    animation: SkeletonAnimation,
+}
+
+impl SkeletonRectConfig {
+    pub fn new(color: impl Into<String>, height: u32) -> Self {
+        SkeletonRectConfig {
+            color: color.into(),
+            height,
+            width: None,
+            highlight: None,
+            animation: SkeletonAnimation::default(),
+        }
+    }
+
+    pub fn width(self, width: impl Into<Option<u32>>) -> Self {
+        SkeletonRectConfig {
+            width: width.into(),
+            ..self
+        }
+    }
+
+    pub fn highlight(self, highlight: impl Into<String>) -> Self {
+        SkeletonRectConfig {
+            highlight: Some(highlight.into()),
+            ..self
+        }
+    }
+
+    pub fn animation(self, animation: SkeletonAnimation) -> Self {
+        SkeletonRectConfig { animation, ..self }
+    }
+}
+
+impl Render for SkeletonRectConfig {
+    fn render(self) -> Result<RenderedLoader, ValidationError> {
+        let colors = skeleton_colors(&self.color, self.highlight.as_deref())?;
+        Size::Px(self.height).validate()?;
+        if let Some(width) = self.width {
+            Size::Px(width).validate()?;
+        }
+        let width = match self.width {
+            Some(width) => format!("{width}px"),
+            None => "100%".to_string(),
+        };
+        let style = format!(
+            "{colors} width: {width}; height: {}px; border-radius: 4px",
+            self.height
+        );
+
+        Ok(RenderedLoader::new(
+            "div",
+            &[("class", self.animation.class()), ("aria-hidden", "true")],
+            style,
+            String::new(),
+            SKELETON_STYLE,
+            &self.animation.class_names(),
+        ))
+    }
+}
+
+/// Renders a rectangular placeholder, e.g for images, charts or buttons.
+///
+/// Takes in required props for both color (#hex) and height (by px). The
+/// width (by px) is optional and the rectangle fills its container when left
+/// out. The highlight color defaults to a lighter shade of `color`.
+///
+/// # Example Usage
+/// ```
+/// use leptos::prelude::*;
+/// use spux::skeletons::SkeletonRect;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <SkeletonRect color="#e0e0e0" height=120 />
+///     }
+/// }
+/// ```
+#[cfg(feature = "leptos")]
+#[component]
+pub fn SkeletonRect(
+    color: &'static str,
+    height: u32,
+    #[prop(optional)] width: Option<u32>,
+    #[prop(default = None, into)] highlight: Option<&'static str>,
+    #[prop(optional)] animation: SkeletonAnimation,
+) -> impl IntoView {
+    let mut config = SkeletonRectConfig::new(color, height)
+        .width(width)
+        .animation(animation);
+    if let Some(highlight) = highlight {
+        config = config.highlight(highlight);
+    }
+    let rendered = render_config(config);
+
+    view! {
+        {loader_style(rendered.css)}
+        <div class=animation.class()
+            aria-hidden="true"
+            style=rendered.style
+        ></div>
+    }
+}
@@ -0,0 +1,150 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+use super::{skeleton_colors, SkeletonAnimation, SKELETON_STYLE};
+use crate::core::{element, Render, RenderedLoader};
+use crate::errors::ValidationError;
+#[cfg(feature = "leptos")]
+use crate::private::{loader_style, render_config};
+use crate::size::Size;
+#[cfg(feature = "leptos")]
+use leptos::prelude::*;
+
+/// A table shaped placeholder, as rendered by the `SkeletonTable` component,
+/// see the [`core`](crate::core) module.
+///
+/// Takes in a color (#hex) and a size (row height by px). `rows` defaults to
+/// 5 and `cols` defaults to 4, while the highlight color defaults to a
+/// lighter shade of `color`.
+///
+/// # Example Usage
+/// ```
+/// use spux::core::render;
+/// use spux::skeletons::SkeletonTableConfig;
+///
+/// let rendered = render(SkeletonTableConfig::new("#e0e0e0", 16).rows(2).cols(3)).unwrap();
+/// assert_eq!(rendered.html.matches("class=\"spux-skeleton").count(), 6);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SkeletonTableConfig {
+    color: String,
+    size: u32,
+    rows: u32,
+    cols: u32,
+    highlight: Option<String>,
+    animation: SkeletonAnimation,
+}
+
+impl SkeletonTableConfig {
+    pub fn new(color: impl Into<String>, size: u32) -> Self {
+        SkeletonTableConfig {
+            color: color.into(),
+            size,
+            rows: 5,
+            cols: 4,
+            highlight: None,
+            animation: SkeletonAnimation::default(),
+        }
+    }
+
+    pub fn rows(self, rows: u32) -> Self {
+        SkeletonTableConfig { rows, ..self }
+    }
+
+    pub fn cols(self, cols: u32) -> Self {
+        SkeletonTableConfig { cols, ..self }
+    }
+
+    pub fn highlight(self, highlight: impl Into<String>) -> Self {
+        SkeletonTableConfig {
+            highlight: Some(highlight.into()),
+            ..self
+        }
+    }
+
+    pub fn animation(self, animation: SkeletonAnimation) -> Self {
+        SkeletonTableConfig { animation, ..self }
+    }
+}
+
+impl Render for SkeletonTableConfig {
+    fn render(self) -> Result<RenderedLoader, ValidationError> {
+        let colors = skeleton_colors(&self.color, self.highlight.as_deref())?;
+        Size::Px(self.size).validate()?;
+        let cell = element(
+            "div",
+            &[
+                ("class", self.animation.class()),
+                (
+                    "style",
+                    &format!("{colors} height: {}px; border-radius: 4px", self.size),
+                ),
+            ],
+            "",
+        );
+        let style = format!(
+            "width: 100%; display: grid; grid-template-columns: repeat({}, minmax(0, 1fr)); gap: {}px",
+            self.cols,
+            self.size / 2
+        );
+
+        Ok(RenderedLoader::new(
+            "div",
+            &[("aria-hidden", "true")],
+            style,
+            cell.repeat((self.rows * self.cols) as usize),
+            SKELETON_STYLE,
+            &self.animation.class_names(),
+        ))
+    }
+}
+
+/// Renders a table shaped placeholder with a grid of cells.
+///
+/// Takes in required props for both color (#hex) and size (row height by px).
+/// `rows` defaults to 5 and `cols` defaults to 4. The highlight color
+/// defaults to a lighter shade of `color`.
+///
+/// # Example Usage
+/// ```
+/// use leptos::prelude::*;
+/// use spux::skeletons::SkeletonTable;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <SkeletonTable color="#e0e0e0" size=16 rows=8 cols=3 />
+///     }
+/// }
+/// ```
+#[cfg(feature = "leptos")]
+#[component]
+pub fn SkeletonTable(
+    color: &'static str,
+    size: u32,
+    #[prop(default = 5)] rows: u32,
+    #[prop(default = 4)] cols: u32,
+    #[prop(default = None, into)] highlight: Option<&'static str>,
+    #[prop(optional)] animation: SkeletonAnimation,
+) -> impl IntoView {
+    let mut config = SkeletonTableConfig::new(color, size)
+        .rows(rows)
+        .cols(cols)
+        .animation(animation);
+    if let Some(highlight) = highlight {
+        config = config.highlight(highlight);
+    }
+    let rendered = render_config(config);
+
+    view! {
+        {loader_style(rendered.css)}
+        <div
+            aria-hidden="true"
+            style=rendered.style
+            inner_html=rendered.content
+        ></div>
+    }
+}
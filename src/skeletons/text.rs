@@ -0,0 +1,163 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+use super::{skeleton_colors, SkeletonAnimation, SKELETON_STYLE};
+use crate::core::{element, Render, RenderedLoader};
+use crate::errors::ValidationError;
+#[cfg(feature = "leptos")]
+use crate::private::{loader_style, render_config};
+use crate::size::Size;
+#[cfg(feature = "leptos")]
+use leptos::prelude::*;
+
+/// A paragraph shaped placeholder, as rendered by the `SkeletonText`
+/// component, see the [`core`](crate::core) module.
+///
+/// Takes in a color (#hex) and a size (line height by px). `lines` defaults
+/// to 3 and `last_line_width` (in % of the container) defaults to 60, while
+/// the highlight color defaults to a lighter shade of `color`.
+///
+/// # Example Usage
+/// ```
+/// use spux::core::render;
+/// use spux::skeletons::SkeletonTextConfig;
+///
+/// let rendered = render(SkeletonTextConfig::new("#e0e0e0", 12).lines(4)).unwrap();
+/// assert_eq!(rendered.html.matches("class=\"spux-skeleton").count(), 4);
+/// assert!(rendered.html.contains("width: 60%"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SkeletonTextConfig {
+    color: String,
+    size: u32,
+    lines: u32,
+    last_line_width: u32,
+    highlight: Option<String>,
+    animation: SkeletonAnimation,
+}
+
+impl SkeletonTextConfig {
+    pub fn new(color: impl Into<String>, size: u32) -> Self {
+        SkeletonTextConfig {
+            color: color.into(),
+            size,
+            lines: 3,
+            last_line_width: 60,
+            highlight: None,
+            animation: SkeletonAnimation::default(),
+        }
+    }
+
+    pub fn lines(self, lines: u32) -> Self {
+        SkeletonTextConfig { lines, ..self }
+    }
+
+    pub fn last_line_width(self, last_line_width: u32) -> Self {
+        SkeletonTextConfig {
+            last_line_width,
+            ..self
+        }
+    }
+
+    pub fn highlight(self, highlight: impl Into<String>) -> Self {
+        SkeletonTextConfig {
+            highlight: Some(highlight.into()),
+            ..self
+        }
+    }
+
+    pub fn animation(self, animation: SkeletonAnimation) -> Self {
+        SkeletonTextConfig { animation, ..self }
+    }
+}
+
+impl Render for SkeletonTextConfig {
+    fn render(self) -> Result<RenderedLoader, ValidationError> {
+        let colors = skeleton_colors(&self.color, self.highlight.as_deref())?;
+        Size::Px(self.size).validate()?;
+        let last_line_width = self.last_line_width.min(100);
+
+        let content = (0..self.lines)
+            .map(|line| {
+                let width = if line + 1 == self.lines {
+                    format!("{last_line_width}%")
+                } else {
+                    "100%".to_string()
+                };
+                let style = format!(
+                    "{colors} width: {width}; height: {}px; border-radius: 4px",
+                    self.size
+                );
+                element(
+                    "div",
+                    &[("class", self.animation.class()), ("style", &style)],
+                    "",
+                )
+            })
+            .collect();
+        let style = format!(
+            "width: 100%; display: flex; flex-direction: column; gap: {}px",
+            self.size / 2
+        );
+
+        Ok(RenderedLoader::new(
+            "div",
+            &[("aria-hidden", "true")],
+            style,
+            content,
+            SKELETON_STYLE,
+            &self.animation.class_names(),
+        ))
+    }
+}
+
+/// Renders a paragraph shaped placeholder made up of several lines.
+///
+/// Takes in required props for both color (#hex) and size (line height by px).
+/// `lines` defaults to 3 and `last_line_width` (in % of the container) defaults
+/// to 60 so that the placeholder reads like the end of a paragraph. The
+/// highlight color defaults to a lighter shade of `color`.
+///
+/// # Example Usage
+/// ```
+/// use leptos::prelude::*;
+/// use spux::skeletons::SkeletonText;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <SkeletonText color="#e0e0e0" size=12 lines=4 last_line_width=40 />
+///     }
+/// }
+/// ```
+#[cfg(feature = "leptos")]
+#[component]
+pub fn SkeletonText(
+    color: &'static str,
+    size: u32,
+    #[prop(default = 3)] lines: u32,
+    #[prop(default = 60)] last_line_width: u32,
+    #[prop(default = None, into)] highlight: Option<&'static str>,
+    #[prop(optional)] animation: SkeletonAnimation,
+) -> impl IntoView {
+    let mut config = SkeletonTextConfig::new(color, size)
+        .lines(lines)
+        .last_line_width(last_line_width)
+        .animation(animation);
+    if let Some(highlight) = highlight {
+        config = config.highlight(highlight);
+    }
+    let rendered = render_config(config);
+
+    view! {
+        {loader_style(rendered.css)}
+        <div
+            aria-hidden="true"
+            style=rendered.style
+            inner_html=rendered.content
+        ></div>
+    }
+}
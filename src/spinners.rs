@@ -6,13 +6,32 @@
  */
 
 mod square;
+#[cfg(feature = "leptos")]
 pub use square::Square;
+pub use square::{square_definition, square_svg};
 
 mod filled_square;
+#[cfg(feature = "leptos")]
 pub use filled_square::FilledSquare;
+pub use filled_square::{filled_square_definition, filled_square_svg};
 
 mod partial_circle;
+#[cfg(feature = "leptos")]
 pub use partial_circle::PartialCircle;
+pub use partial_circle::{partial_circle_definition, partial_circle_svg};
 
 mod triangle;
+#[cfg(feature = "leptos")]
 pub use triangle::Triangle;
+pub use triangle::{triangle_definition, triangle_svg};
+
+use crate::keyframes::{Keyframes, Unit};
+
+// rotates the loader through the given angles (by deg) over one cycle
+fn spin_keyframes(name: &str, rotations: [u32; 3]) -> Keyframes {
+    let [start, middle, end] = rotations;
+    Keyframes::new(name)
+        .at(0.pct(), |step| step.rotate(start.deg()))
+        .at(50.pct(), |step| step.rotate(middle.deg()))
+        .at(100.pct(), |step| step.rotate(end.deg()))
+}
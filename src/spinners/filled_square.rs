@@ -4,69 +4,56 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  */
-use crate::types::Validation;
-use leptos::prelude::*;
-use std::process;
+use super::spin_keyframes;
+use crate::shapes::Shape;
+use crate::spux_loader;
+use crate::svg::SvgLoader;
 
-/// Renders a filled square and rotates it immediately on
-/// the screen.
-///
-/// Takes in required props for both color (#hex) and size (by px)
-///
-/// # Example Usage
-/// ```
-/// use leptos::prelude::*;
-/// use spux::spinners::FilledSquare;
-///
-/// #[component]
-/// fn App() -> impl IntoView {
-///     view! {
-///         <FilledSquare color="#000000" size=10 />
-///     }
-/// }
-/// ```
-
-#[component]
-pub fn FilledSquare(color: &'static str, size: u32) -> impl IntoView {
-    // we intentionally create a compile time process exit to inform users
-    // that an incorrect #hex color code was used
-    let Ok(_) = color.validate_color_code() else {
-        eprintln!("Spux Error - Color should be in the #hex format (e.g #000000) for black");
-        process::exit(1);
-    };
-
-    let style = format!(
-        "
-        .spux-spinner-filled-square {{
-            display:flex;
-            width: {size}px;
-            height: {size}px;
-            transform: rotate(45deg);
-            animation: spux-spinner-filled-square 1400ms ease-in-out forwards;
-            animation-iteration-count: infinite;
-        }}
-
-        @keyframes spux-spinner-filled-square  {{
-            0% {{
+spux_loader! {
+    /// Renders a filled square and rotates it immediately on
+    /// the screen.
+    ///
+    /// Takes in required props for size (by px, or by em to render it inline with
+    /// the surrounding text) and an optional color (#hex), which defaults to the
+    /// color of the surrounding text. The optional duration (by ms) and label
+    /// change the speed of the animation and what is announced to assistive
+    /// technologies.
+    ///
+    /// # Example Usage
+    /// ```
+    /// use leptos::prelude::*;
+    /// use spux::spinners::FilledSquare;
+    ///
+    /// #[component]
+    /// fn App() -> impl IntoView {
+    ///     view! {
+    ///         <FilledSquare color="#000000" size=10 />
+    ///     }
+    /// }
+    /// ```
+    pub FilledSquare => filled_square_definition {
+        class: "spux-spinner-filled-square",
+        svg: filled_square_svg(),
+        glyphs: &["▖", "▘", "▝", "▗"],
+        style: "
+            .spux-spinner-filled-square {
+                display:flex;
+                width: var(--spux-size);
+                height: var(--spux-size);
+                background-color: var(--spux-color);
                 transform: rotate(45deg);
-            }}
-            50% {{
-                transform: rotate(90deg);
-            }}
-            100% {{
-                transform: rotate(135deg);
-            }}
-        }}
-
-    "
-    );
-
-    view! {
-        <style>
-            {style}
-        </style>
-        <div class="spux-spinner-filled-square"
-            style:background-color=color
-        ></div>
+                animation: spux-spinner-filled-square var(--spux-duration) ease-in-out forwards;
+                animation-iteration-count: infinite;
+            }
+        ",
+        keyframes: spin_keyframes("spux-spinner-filled-square", [45, 90, 135]),
     }
 }
+
+/// The SVG drawing of the [`FilledSquare`] loader, see the [`svg`](crate::svg) module.
+pub fn filled_square_svg() -> SvgLoader {
+    SvgLoader::new(
+        Shape::Square.svg_shape(66.0),
+        spin_keyframes("spux-svg-filled-square", [45, 90, 135]),
+    )
+}
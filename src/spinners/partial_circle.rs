@@ -4,72 +4,63 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  */
-use crate::types::Validation;
-use leptos::prelude::*;
-use std::process;
+use super::spin_keyframes;
+use crate::keyframes::Easing;
+use crate::shapes::Shape;
+use crate::spux_loader;
+use crate::svg::SvgLoader;
 
-/// Renders a partial circle and rotates it immediately on
-/// the screen.
-///
-/// Takes in required props for both color (#hex) and size (by px)
-///
-/// # Example Usage
-/// ```
-/// use leptos::prelude::*;
-/// use spux::spinners::PartialCircle;
-///
-/// #[component]
-/// fn App() -> impl IntoView {
-///     view! {
-///         <PartialCircle color="#000000" size=10 />
-///     }
-/// }
-/// ```
-
-#[component]
-pub fn PartialCircle(color: &'static str, size: u32) -> impl IntoView {
-    // we intentionally create a compile time process exit to inform users
-    // that an incorrect #hex color code was used
-    let Ok(_) = color.validate_color_code() else {
-        eprintln!("Spux Error - Color should be in the #hex format (e.g #000000) for black");
-        process::exit(1);
-    };
-
-    let border_color = format!("transparent {color} {color} {color}");
-    let style = format!(
-        "
-        .spux-partial-circle {{
-            background: transparent;
-            display:flex;
-            width: {size}px;
-            height: {size}px;
-            border-radius: 100%;
-            transform: rotate(45deg);
-            animation: spux-partial-circle 1400ms linear forwards;
-            animation-iteration-count: infinite;
-        }}
-
-        @keyframes spux-partial-circle {{
-            0% {{
+spux_loader! {
+    /// Renders a partial circle and rotates it immediately on
+    /// the screen.
+    ///
+    /// Takes in required props for size (by px, or by em to render it inline with
+    /// the surrounding text) and an optional color (#hex), which defaults to the
+    /// color of the surrounding text. The optional duration (by ms) and label
+    /// change the speed of the animation and what is announced to assistive
+    /// technologies.
+    ///
+    /// # Example Usage
+    /// ```
+    /// use leptos::prelude::*;
+    /// use spux::spinners::PartialCircle;
+    ///
+    /// #[component]
+    /// fn App() -> impl IntoView {
+    ///     view! {
+    ///         <PartialCircle color="#000000" size=10 />
+    ///     }
+    /// }
+    /// ```
+    pub PartialCircle => partial_circle_definition {
+        class: "spux-partial-circle",
+        svg: partial_circle_svg(),
+        glyphs: &["◜", "◝", "◞", "◟"],
+        style: "
+            .spux-partial-circle {
+                background: transparent;
+                display:flex;
+                width: var(--spux-size);
+                height: var(--spux-size);
+                border: 2px solid;
+                border-color: transparent var(--spux-color) var(--spux-color) var(--spux-color);
+                border-radius: 100%;
                 transform: rotate(45deg);
-            }}
-            50% {{
-                transform: rotate(270deg);
-            }}
-            100% {{
-                transform: rotate(405deg);
-            }}
-        }}
-    "
-    );
-
-    view! {
-        <style>
-            {style}
-        </style>
-        <div class="spux-partial-circle"
-            style:border="2px solid"
-            style:border-color=border_color
-        ></div>
+                animation: spux-partial-circle var(--spux-duration) linear forwards;
+                animation-iteration-count: infinite;
+            }
+        ",
+        keyframes: spin_keyframes("spux-partial-circle", [45, 270, 405]),
     }
 }
+
+/// The SVG drawing of the [`PartialCircle`] loader, see the [`svg`](crate::svg) module.
+pub fn partial_circle_svg() -> SvgLoader {
+    SvgLoader::new(
+        Shape::Circle.svg_shape(88.0),
+        spin_keyframes("spux-svg-partial-circle", [45, 270, 405]),
+    )
+    .outline(6.0)
+    .dash(75.0)
+    .easing(Easing::Linear)
+}
@@ -4,71 +4,58 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  */
-use crate::types::Validation;
-use leptos::prelude::*;
-use std::process;
+use super::spin_keyframes;
+use crate::shapes::Shape;
+use crate::spux_loader;
+use crate::svg::SvgLoader;
 
-/// Renders a square and rotates it immediately on
-/// the screen.
-///
-/// Takes in required props for both color (#hex) and size (by px)
-///
-/// # Example Usage
-/// ```
-/// use leptos::prelude::*;
-/// use spux::spinners::Square;
-///
-/// #[component]
-/// fn App() -> impl IntoView {
-///     view! {
-///         <Square color="#000000" size=10 />
-///     }
-/// }
-/// ```
-
-#[component]
-pub fn Square(color: &'static str, size: u32) -> impl IntoView {
-    // we intentionally create a compile time process exit to inform users
-    // that an incorrect #hex color code was used
-    let Ok(_) = color.validate_color_code() else {
-        eprintln!("Spux Error - Color should be in the #hex format (e.g #000000) for black");
-        process::exit(1);
-    };
-
-    let style = format!(
-        "
-        .spux-spinner-square {{
-            background: transparent;
-            display:flex;
-            width: {size}px;
-            height: {size}px;
-            transform: rotate(45deg);
-            animation: spux-spinner-square 1400ms ease-in-out forwards;
-            animation-iteration-count: infinite;
-        }}
-
-        @keyframes spux-spinner-square {{
-            0% {{
+spux_loader! {
+    /// Renders a square and rotates it immediately on
+    /// the screen.
+    ///
+    /// Takes in required props for size (by px, or by em to render it inline with
+    /// the surrounding text) and an optional color (#hex), which defaults to the
+    /// color of the surrounding text. The optional duration (by ms) and label
+    /// change the speed of the animation and what is announced to assistive
+    /// technologies.
+    ///
+    /// # Example Usage
+    /// ```
+    /// use leptos::prelude::*;
+    /// use spux::spinners::Square;
+    ///
+    /// #[component]
+    /// fn App() -> impl IntoView {
+    ///     view! {
+    ///         <Square color="#000000" size=10 />
+    ///     }
+    /// }
+    /// ```
+    pub Square => square_definition {
+        class: "spux-spinner-square",
+        svg: square_svg(),
+        glyphs: &["◰", "◳", "◲", "◱"],
+        style: "
+            .spux-spinner-square {
+                background: transparent;
+                display:flex;
+                width: var(--spux-size);
+                height: var(--spux-size);
+                border: 1px solid var(--spux-color);
                 transform: rotate(45deg);
-            }}
-            50% {{
-                transform: rotate(90deg);
-            }}
-            100% {{
-                transform: rotate(135deg);
-            }}
-        }}
-
-    "
-    );
-
-    view! {
-        <style>
-            {style}
-        </style>
-        <div class="spux-spinner-square"
-            style:border="1px solid"
-            style:border-color=color
-        ></div>
+                animation: spux-spinner-square var(--spux-duration) ease-in-out forwards;
+                animation-iteration-count: infinite;
+            }
+        ",
+        keyframes: spin_keyframes("spux-spinner-square", [45, 90, 135]),
     }
 }
+
+/// The SVG drawing of the [`Square`] loader, see the [`svg`](crate::svg) module.
+pub fn square_svg() -> SvgLoader {
+    SvgLoader::new(
+        Shape::Square.svg_shape(66.0),
+        spin_keyframes("spux-svg-square", [45, 90, 135]),
+    )
+    .outline(4.0)
+}
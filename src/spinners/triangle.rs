@@ -4,78 +4,69 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  */
-use crate::types::Validation;
-use leptos::prelude::*;
-use std::process;
+use super::spin_keyframes;
+use crate::keyframes::{Keyframes, Length, Unit};
+use crate::shapes::Shape;
+use crate::spux_loader;
+use crate::svg::SvgLoader;
 
-/// Renders a square and rotates it immediately on
-/// the screen.
-///
-/// Takes in required props for both color (#hex) and size (by px)
-///
-/// # Example Usage
-/// ```
-/// use leptos::prelude::*;
-/// use spux::spinners::Triangle;
-///
-/// #[component]
-/// fn App() -> impl IntoView {
-///     view! {
-///         <Triangle color="#000000" size=10 />
-///     }
-/// }
-/// ```
-
-#[component]
-pub fn Triangle(color: &'static str, size: u32) -> impl IntoView {
-    // we intentionally create a compile time process exit to inform users
-    // that an incorrect #hex color code was used
-    let Ok(_) = color.validate_color_code() else {
-        eprintln!("Spux Error - Color should be in the #hex format (e.g #000000) for black");
-        process::exit(1);
-    };
-
-    let border_color = format!("{}px solid {}", &size * 2, color);
-    let half_size = size / 2;
-    let style = format!(
-        "
-            .spux-spinner-triangle {{
+spux_loader! {
+    /// Renders a square and rotates it immediately on
+    /// the screen.
+    ///
+    /// Takes in required props for size (by px, or by em to render it inline with
+    /// the surrounding text) and an optional color (#hex), which defaults to the
+    /// color of the surrounding text. The optional duration (by ms) and label
+    /// change the speed of the animation and what is announced to assistive
+    /// technologies.
+    ///
+    /// # Example Usage
+    /// ```
+    /// use leptos::prelude::*;
+    /// use spux::spinners::Triangle;
+    ///
+    /// #[component]
+    /// fn App() -> impl IntoView {
+    ///     view! {
+    ///         <Triangle color="#000000" size=10 />
+    ///     }
+    /// }
+    /// ```
+    pub Triangle => triangle_definition {
+        class: "spux-spinner-triangle",
+        scale: 2,
+        svg: triangle_svg(),
+        glyphs: &["◢", "◣", "◤", "◥"],
+        style: "
+            .spux-spinner-triangle {
                 background: transparent;
-                border: {size}px solid transparent;
+                border: var(--spux-size) solid transparent;
                 border-top: 0;
+                border-bottom: calc(var(--spux-size) * 2) solid var(--spux-color);
                 display:flex;
                 width: 0px;
                 height: 0px;
                 transform: rotate(45deg);
-                animation: spux-spinner-triangle 1400ms ease-in-out forwards;
+                animation: spux-spinner-triangle var(--spux-duration) ease-in-out forwards;
                 animation-iteration-count: infinite;
-            }}
-
-            @keyframes spux-spinner-triangle  {{
-                0% {{
-                    transform: rotate(45deg);
-                    margin-top:0px;
-                    margin-right:0px;
-                }}
-                50% {{
-                    transform: rotate(270deg);
-                    margin-top:0px;
-                    margin-right:{half_size}px;
-                }}
-                100% {{
-                    transform: rotate(405deg);
-                    margin-top:0px;
-                    margin-right:0px;
-                }}
-            }}
-        "
-    );
-    view! {
-        <style>
-            {style}
-        </style>
-        <div class="spux-spinner-triangle"
-            style:border-bottom=border_color
-        ></div>
+            }
+        ",
+        keyframes: Keyframes::new("spux-spinner-triangle")
+            .at(0.pct(), |step| step.rotate(45.deg()).margin_top(0.px()).margin_right(0.px()))
+            .at(50.pct(), |step| {
+                step.rotate(270.deg())
+                    .margin_top(0.px())
+                    .margin_right(Length::spux_size().divided(2.0))
+            })
+            .at(100.pct(), |step| step.rotate(405.deg()).margin_top(0.px()).margin_right(0.px())),
     }
 }
+
+/// The SVG drawing of the [`Triangle`] loader, see the [`svg`](crate::svg) module.
+pub fn triangle_svg() -> SvgLoader {
+    SvgLoader::new(
+        Shape::Triangle.svg_shape(86.0),
+        spin_keyframes("spux-svg-triangle", [45, 270, 405]),
+    )
+    .stroke(4.0)
+}
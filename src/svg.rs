@@ -0,0 +1,367 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+/*!
+ Rendering loaders as SVG.
+
+ Every loader declared with `spux_loader!` with an `svg` drawing (which includes
+ the spinners and pulsers) can render as inline SVG instead of styled elements,
+ by passing `renderer=Renderer::Svg` or by enabling the `svg` feature (which
+ makes SVG the default renderer). SVG loaders are crisp at any size, have
+ rounded strokes (the `--spux-linecap` custom property overrides the
+ `stroke-linecap`, which defaults to `round`) and are animated with CSS.
+
+ The same SVG can be rendered as a standalone string or data URI, e.g for an
+ `<img>` or a CSS `background-image`. The SVG drawings of the built-in loaders
+ are exported next to them (e.g `spux::spinners::partial_circle_svg`).
+
+```
+use spux::keyframes::{Keyframes, Unit};
+use spux::svg::{SvgLoader, SvgShape};
+
+let dot = SvgLoader::new(
+    SvgShape::Circle { cx: 50.0, cy: 50.0, r: 40.0 },
+    Keyframes::new("brand-dot")
+        .at(0.pct(), |step| step.opacity(1.0))
+        .at(50.pct(), |step| step.opacity(0.2))
+        .at(100.pct(), |step| step.opacity(1.0)),
+);
+
+let svg = dot.render("#000000", 32, 1400).unwrap();
+assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+
+let data_uri = dot.data_uri("#000000", 32, 1400).unwrap();
+assert!(data_uri.starts_with("data:image/svg+xml,%3Csvg"));
+assert!(dot.data_uri("black", 32, 1400).is_err());
+assert!(dot.render("#000000", 0, 1400).is_err());
+```
+*/
+use crate::errors::ValidationError;
+use crate::keyframes::{Easing, Keyframes, Length, Property, Transform, Value};
+use crate::size::Size;
+use crate::types::Validation;
+
+/// How a loader is rendered.
+///
+/// More renderers may be added, so matching on it needs a wildcard arm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Renderer {
+    /// Styled elements animated with CSS (the default).
+    Css,
+    /// An inline SVG animated with CSS (the default with the `svg` feature).
+    ///
+    /// Only loaders with an SVG drawing can render as SVG, i.e the spinners
+    /// and pulsers declared with `spux_loader!` and custom loaders given an
+    /// `svg`. The other loaders (e.g grids, morphers, 3D and progress) always
+    /// render as elements, and passing `renderer=Renderer::Svg` to a loader
+    /// without a drawing exits with an error.
+    Svg,
+    /// An inline SVG animated with the Web Animations API, without a
+    /// stylesheet, see the [`web_animations`](crate::web_animations) module.
+    /// Same as with `Svg`, only loaders with an SVG drawing support it.
+    #[cfg(feature = "web_animations")]
+    WebAnimations(crate::web_animations::LoaderAnimation),
+}
+
+impl Default for Renderer {
+    fn default() -> Self {
+        if cfg!(feature = "svg") {
+            Renderer::Svg
+        } else {
+            Renderer::Css
+        }
+    }
+}
+
+/// The shape of an SVG loader, within a `0 0 100 100` viewBox.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SvgShape {
+    Rect {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    },
+    Circle {
+        cx: f64,
+        cy: f64,
+        r: f64,
+    },
+    Polygon(Vec<(f64, f64)>),
+}
+
+impl SvgShape {
+    /// The center of the bounding box of the shape, which it is transformed
+    /// around.
+    pub fn center(&self) -> (f64, f64) {
+        match self {
+            SvgShape::Rect {
+                x,
+                y,
+                width,
+                height,
+            } => (x + width / 2.0, y + height / 2.0),
+            SvgShape::Circle { cx, cy, .. } => (*cx, *cy),
+            SvgShape::Polygon(points) => {
+                let bounds = |axis: fn(&(f64, f64)) -> f64| {
+                    let values = points.iter().map(axis);
+                    let min = values.clone().fold(f64::INFINITY, f64::min);
+                    let max = values.fold(f64::NEG_INFINITY, f64::max);
+                    (min + max) / 2.0
+                };
+                (bounds(|point| point.0), bounds(|point| point.1))
+            }
+        }
+    }
+
+    fn markup(&self, class: &str, attributes: &str) -> String {
+        match self {
+            SvgShape::Rect {
+                x,
+                y,
+                width,
+                height,
+            } => format!(
+                "<rect class=\"{class}\" x=\"{x}\" y=\"{y}\" width=\"{width}\" height=\"{height}\"{attributes}/>"
+            ),
+            SvgShape::Circle { cx, cy, r } => format!(
+                "<circle class=\"{class}\" cx=\"{cx}\" cy=\"{cy}\" r=\"{r}\"{attributes}/>"
+            ),
+            SvgShape::Polygon(points) => {
+                let points = points
+                    .iter()
+                    .map(|(x, y)| format!("{x},{y}"))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                format!("<polygon class=\"{class}\" points=\"{points}\"{attributes}/>")
+            }
+        }
+    }
+}
+
+/// A frame of an SVG loader, see [`SvgLoader::frame`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgFrame {
+    /// The transforms that are applied around the center of the shape, in
+    /// order. Transforms without a 2d equivalent (e.g `rotateX`) are left out.
+    pub transforms: Vec<Transform>,
+    pub opacity: f64,
+}
+
+/// The SVG drawing of a loader: a shape that is filled (or outlined) with
+/// `--spux-color` and animated by `keyframes` over `--spux-duration`.
+///
+/// The shape is transformed around its own center, and is classed by the
+/// name of the keyframes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgLoader {
+    shape: SvgShape,
+    keyframes: Keyframes,
+    fill: bool,
+    stroke: Option<f64>,
+    dash: Option<f64>,
+    easing: Easing,
+}
+
+impl SvgLoader {
+    /// A filled shape, animated with an ease-in-out.
+    pub fn new(shape: SvgShape, keyframes: Keyframes) -> Self {
+        SvgLoader {
+            shape,
+            keyframes,
+            fill: true,
+            stroke: None,
+            dash: None,
+            easing: Easing::EaseInOut,
+        }
+    }
+
+    /// Only draws the outline of the shape, with a stroke of `width`.
+    pub fn outline(self, width: f64) -> Self {
+        SvgLoader {
+            fill: false,
+            stroke: Some(width),
+            ..self
+        }
+    }
+
+    /// Draws a stroke of `width` around the (filled) shape, e.g to round its
+    /// corners.
+    pub fn stroke(self, width: f64) -> Self {
+        SvgLoader {
+            stroke: Some(width),
+            ..self
+        }
+    }
+
+    /// Only draws the first `visible` percent of the stroke.
+    pub fn dash(self, visible: f64) -> Self {
+        SvgLoader {
+            dash: Some(visible.clamp(0.0, 100.0)),
+            ..self
+        }
+    }
+
+    pub fn easing(self, easing: Easing) -> Self {
+        SvgLoader { easing, ..self }
+    }
+
+    pub fn shape(&self) -> &SvgShape {
+        &self.shape
+    }
+
+    pub fn keyframes(&self) -> &Keyframes {
+        &self.keyframes
+    }
+
+    pub fn is_filled(&self) -> bool {
+        self.fill
+    }
+
+    /// The width of the stroke, if the shape is stroked.
+    pub fn stroke_width(&self) -> Option<f64> {
+        self.stroke
+    }
+
+    /// The visible percent of the stroke, if it is dashed.
+    pub fn visible_stroke(&self) -> Option<f64> {
+        self.dash
+    }
+
+    pub fn timing(&self) -> Easing {
+        self.easing
+    }
+
+    /// The 2d transforms and opacity of the loader at a `progress` from 0 to
+    /// 1 through one cycle, e.g for drawing it outside of a browser.
+    pub fn frame(&self, progress: f64) -> SvgFrame {
+        let step = self.keyframes.sample(progress, self.easing);
+        let transforms = step
+            .transforms()
+            .iter()
+            .filter(|transform| {
+                matches!(
+                    transform,
+                    Transform::Rotate(_)
+                        | Transform::Scale(_)
+                        | Transform::TranslateX(Length::Px(_))
+                        | Transform::TranslateY(Length::Px(_))
+                )
+            })
+            .copied()
+            .collect();
+        let opacity = step
+            .properties()
+            .iter()
+            .find_map(|(property, value)| match (property, value) {
+                (Property::Opacity, Value::Number(opacity)) => Some(*opacity),
+                _ => None,
+            })
+            .unwrap_or(1.0);
+        SvgFrame {
+            transforms,
+            opacity,
+        }
+    }
+
+    /// The SVG markup of the loader, without the surrounding `<svg>`.
+    pub fn body(&self) -> String {
+        let path_length = match self.dash {
+            Some(_) => " pathLength=\"100\"",
+            None => "",
+        };
+        self.shape.markup(self.keyframes.name(), path_length)
+    }
+
+    // the body with presentation attributes instead of a stylesheet, for
+    // loaders that are animated without one
+    #[cfg(feature = "web_animations")]
+    pub(crate) fn inline_body(&self, color: &str) -> String {
+        let fill = if self.fill { color } else { "none" };
+        let mut attributes = format!(" fill=\"{fill}\"");
+        if let Some(width) = self.stroke {
+            attributes += &format!(" stroke=\"{color}\" stroke-width=\"{width}\"");
+        }
+        if let Some(visible) = self.dash {
+            attributes += &format!(
+                " stroke-dasharray=\"{visible} {}\" pathLength=\"100\"",
+                100.0 - visible
+            );
+        }
+        let (x, y) = self.shape.center();
+        attributes += &format!(
+            " stroke-linecap=\"round\" stroke-linejoin=\"round\" transform-origin=\"{x} {y}\""
+        );
+        self.shape.markup(self.keyframes.name(), &attributes)
+    }
+
+    /// The stylesheet that animates the loader.
+    pub fn css(&self) -> Result<String, ValidationError> {
+        let name = self.keyframes.name();
+        let fill = if self.fill {
+            "var(--spux-color)"
+        } else {
+            "none"
+        };
+        let stroke = match (self.stroke, self.dash) {
+            (Some(width), Some(visible)) => format!(
+                "stroke:var(--spux-color);stroke-width:{width};stroke-dasharray:{visible} {};",
+                100.0 - visible
+            ),
+            (Some(width), None) => format!("stroke:var(--spux-color);stroke-width:{width};"),
+            (None, _) => String::new(),
+        };
+        Ok(format!(
+            ".{name}{{fill:{fill};{stroke}stroke-linecap:var(--spux-linecap,round);\
+             stroke-linejoin:round;transform-box:fill-box;transform-origin:center;\
+             animation:{name} var(--spux-duration) {} infinite}}{}",
+            self.easing.css(),
+            self.keyframes.to_css()?
+        ))
+    }
+    /// Renders the loader as a standalone SVG document of the given color
+    /// (#hex), size and duration (by ms).
+    pub fn render(
+        &self,
+        color: &str,
+        size: impl Into<Size>,
+        duration: u32,
+    ) -> Result<String, ValidationError> {
+        color.validate_color_code()?;
+        let size = size.into();
+        size.validate()?;
+        Ok(format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 100 100\" \
+             width=\"{size}\" height=\"{size}\" \
+             style=\"--spux-color:{color};--spux-duration:{duration}ms\">\
+             <style>{}</style>{}</svg>",
+            self.css()?,
+            self.body()
+        ))
+    }
+
+    /// Renders the loader as a `data:image/svg+xml` URI, see [`render`](Self::render).
+    pub fn data_uri(
+        &self,
+        color: &str,
+        size: impl Into<Size>,
+        duration: u32,
+    ) -> Result<String, ValidationError> {
+        let svg = self.render(color, size, duration)?;
+        let encoded = svg
+            .bytes()
+            .map(|byte| match byte {
+                b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                    (byte as char).to_string()
+                }
+                byte => format!("%{byte:02X}"),
+            })
+            .collect::<String>();
+        Ok(format!("data:image/svg+xml,{encoded}"))
+    }
+}
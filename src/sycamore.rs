@@ -0,0 +1,104 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+/*!
+ The Spux loaders as Sycamore components.
+
+ With the `sycamore` feature, every family of loaders (the `spinners`,
+ `pulsers`, `progress` bars, `skeletons`, `text`, `grids`, `morphers` and
+ `three_d` loaders) comes as Sycamore components with the same props as their
+ Leptos counterparts, rendered from the same definitions and configs (see the
+ [`core`](crate::core) module).
+
+```rust,ignore
+use spux::sycamore::pulsers::Circle;
+use sycamore::prelude::*;
+
+#[component]
+fn App() -> View {
+    view! {
+        Circle(color="#000000", size=10)
+    }
+}
+```
+
+ Custom loaders declared with `spux_loader!` are rendered by [`SpuxLoader`]
+ from their definition.
+*/
+
+// declares a Sycamore component for a built-in loader, from its definition
+#[cfg(any(feature = "spinners", feature = "pulsers"))]
+macro_rules! sycamore_loader {
+    ($(#[$meta:meta])* $name:ident => $definition:path) => {
+        $(#[$meta])*
+        #[::sycamore::prelude::component]
+        pub fn $name(props: $crate::sycamore::LoaderProps) -> ::sycamore::prelude::View {
+            $crate::sycamore::render($definition(), props)
+        }
+    };
+}
+
+mod spux_loader;
+#[cfg(any(feature = "spinners", feature = "pulsers"))]
+use spux_loader::render;
+pub use spux_loader::{LoaderProps, SpuxLoader, SpuxLoaderProps};
+
+#[cfg(feature = "spinners")]
+pub mod spinners;
+
+#[cfg(feature = "pulsers")]
+pub mod pulsers;
+
+#[cfg(feature = "progress")]
+pub mod progress;
+
+#[cfg(feature = "skeletons")]
+pub mod skeletons;
+
+#[cfg(feature = "text")]
+pub mod text;
+
+#[cfg(feature = "grids")]
+pub mod grids;
+
+#[cfg(feature = "morphers")]
+pub mod morphers;
+
+#[cfg(feature = "three_d")]
+pub mod three_d;
+
+// renders a component from the markup and CSS that `core::render` renders
+// its config into
+#[cfg(any(
+    feature = "skeletons",
+    feature = "text",
+    feature = "grids",
+    feature = "morphers",
+    feature = "three_d"
+))]
+fn render_component(config: impl crate::core::Render) -> ::sycamore::prelude::View {
+    use ::sycamore::prelude::*;
+    use ::sycamore::web::{HtmlNode, ViewHtmlNode};
+
+    let rendered = crate::private::render_config(config);
+    let mut root = match rendered.tag.as_str() {
+        "svg" => HtmlNode::create_element_ns("http://www.w3.org/2000/svg", rendered.tag.into()),
+        _ => HtmlNode::create_element(rendered.tag.into()),
+    };
+    for (name, value) in rendered.attributes {
+        root.set_attribute(name.into(), value.into());
+    }
+    if !rendered.style.is_empty() {
+        root.set_attribute("style".into(), rendered.style.into());
+    }
+    root.set_inner_html(rendered.content.into());
+
+    View::from((
+        view! { style(dangerously_set_inner_html=rendered.css) },
+        View::from_node(root),
+    ))
+}
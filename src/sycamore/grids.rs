@@ -0,0 +1,84 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! The [`grids`](crate::grids) as Sycamore components.
+
+use super::render_component;
+use crate::grids::{cube_grid_config, folding_cube_config, GridConfig, GridEffect, Stagger};
+use crate::size::Size;
+use sycamore::prelude::*;
+
+/// The props of [`Grid`], which are the same as with Leptos.
+#[derive(Props)]
+pub struct GridProps {
+    #[prop(default, setter(strip_option, into))]
+    pub color: Option<String>,
+    #[prop(setter(into))]
+    pub size: Size,
+    #[prop(default = 3)]
+    pub rows: u32,
+    #[prop(default = 3)]
+    pub cols: u32,
+    #[prop(default = 2)]
+    pub gap: u32,
+    #[prop(default)]
+    pub pattern: Stagger,
+    #[prop(default)]
+    pub effect: GridEffect,
+    #[prop(default = 1400)]
+    pub duration: u32,
+    #[prop(default = "Loading".to_string(), setter(into))]
+    pub label: String,
+}
+
+/// The props of [`CubeGrid`] and [`FoldingCube`], which are the same as with
+/// Leptos.
+#[derive(Props)]
+pub struct GridPresetProps {
+    #[prop(default, setter(strip_option, into))]
+    pub color: Option<String>,
+    #[prop(setter(into))]
+    pub size: Size,
+    #[prop(default = "Loading".to_string(), setter(into))]
+    pub label: String,
+}
+
+/// Renders a grid of filled cells that animate one after the other, see
+/// `spux::grids::Grid`.
+#[component]
+pub fn Grid(props: GridProps) -> View {
+    let config = GridConfig::new(props.size)
+        .rows(props.rows)
+        .cols(props.cols)
+        .gap(props.gap)
+        .pattern(props.pattern)
+        .effect(props.effect)
+        .duration(props.duration);
+    render_grid(config, props.color, props.label)
+}
+
+/// Renders a 3x3 grid of cubes that shrink away and grow back in a diagonal
+/// wave, see `spux::grids::CubeGrid`.
+#[component]
+pub fn CubeGrid(props: GridPresetProps) -> View {
+    render_grid(cube_grid_config(props.size), props.color, props.label)
+}
+
+/// Renders a cube split into 4 cells that fold in and out of view one after
+/// the other, see `spux::grids::FoldingCube`.
+#[component]
+pub fn FoldingCube(props: GridPresetProps) -> View {
+    render_grid(folding_cube_config(props.size), props.color, props.label)
+}
+
+fn render_grid(config: GridConfig, color: Option<String>, label: String) -> View {
+    let config = config.label(label);
+    render_component(match color {
+        Some(color) => config.color(color),
+        None => config,
+    })
+}
@@ -0,0 +1,43 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! The [`morphers`](crate::morphers) as Sycamore components.
+
+use super::render_component;
+use crate::morphers::MorphConfig;
+use crate::shapes::Shape;
+use crate::size::Size;
+use sycamore::prelude::*;
+
+/// The props of [`Morph`], which are the same as with Leptos.
+#[derive(Props)]
+pub struct MorphProps {
+    #[prop(default, setter(strip_option, into))]
+    pub color: Option<String>,
+    #[prop(setter(into))]
+    pub size: Size,
+    #[prop(default = vec![Shape::Square, Shape::Circle, Shape::Diamond], setter(into))]
+    pub shapes: Vec<Shape>,
+    #[prop(default = 1400)]
+    pub duration: u32,
+    #[prop(default = "Loading".to_string(), setter(into))]
+    pub label: String,
+}
+
+/// Renders a filled shape that morphs into the next shape of the sequence,
+/// see `spux::morphers::Morph`.
+#[component]
+pub fn Morph(props: MorphProps) -> View {
+    let mut config = MorphConfig::new(props.size)
+        .shapes(props.shapes)
+        .duration(props.duration)
+        .label(props.label);
+    if let Some(color) = props.color {
+        config = config.color(color);
+    }
+    render_component(config)
+}
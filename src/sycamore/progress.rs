@@ -0,0 +1,138 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! The [`progress`](crate::progress) bars as Sycamore components.
+
+use crate::private::render_config;
+use crate::progress::{CircularProgressConfig, LinearProgressConfig};
+use sycamore::prelude::*;
+
+/// The props of [`LinearProgress`] and [`CircularProgress`], which are the
+/// same as with Leptos: a required `size` (by px), an optional `color` (#hex)
+/// that defaults to the color of the surrounding text, an optional `value`
+/// and `buffer` (0.0 to 1.0), which can be reactive, and an optional `label`
+/// (defaults to "Loading").
+#[derive(Props)]
+pub struct ProgressProps {
+    #[prop(default, setter(strip_option, into))]
+    pub color: Option<String>,
+    pub size: u32,
+    #[prop(default = None.into(), setter(into))]
+    pub value: MaybeDyn<Option<f64>>,
+    #[prop(default = None.into(), setter(into))]
+    pub buffer: MaybeDyn<Option<f64>>,
+    #[prop(default = "Loading".to_string(), setter(into))]
+    pub label: String,
+}
+
+/// Renders a horizontal progress bar that fills the width of its
+/// container, see `spux::progress::LinearProgress`.
+#[component]
+pub fn LinearProgress(props: ProgressProps) -> View {
+    let ProgressProps {
+        color,
+        size,
+        value,
+        buffer,
+        label,
+    } = props;
+    let mut config = LinearProgressConfig::new(size).label(&label);
+    if let Some(color) = color {
+        config = config.color(color);
+    }
+    let rendered = render_config(config.clone());
+
+    // the bars are kept (rather than rendered again) when the value changes,
+    // so that their width transitions smoothly
+    let current = move || config.clone().value(value.get()).buffer(buffer.get());
+    let (value_now, buffer_style) = (current.clone(), current.clone());
+    let (bar_class, bar_style) = (current.clone(), current);
+
+    view! {
+        style(dangerously_set_inner_html=rendered.css)
+        div(
+            class="spux-linear-progress",
+            role="progressbar",
+            aria-label=label,
+            aria-valuemin="0",
+            aria-valuemax="100",
+            aria-valuenow=move || value_now().value_now(),
+            style=rendered.style,
+        ) {
+            div(class="spux-linear-progress-buffer", style=move || buffer_style().buffer_style())
+            div(class=move || bar_class().bar_class(), style=move || bar_style().bar_style())
+        }
+    }
+}
+
+/// Renders a circular progress ring, see `spux::progress::CircularProgress`.
+#[component]
+pub fn CircularProgress(props: ProgressProps) -> View {
+    let ProgressProps {
+        color,
+        size,
+        value,
+        buffer,
+        label,
+    } = props;
+    let mut config = CircularProgressConfig::new(size).label(&label);
+    if let Some(color) = color {
+        config = config.color(color);
+    }
+    let rendered = render_config(config.clone());
+
+    let (center, radius) = (config.center(), config.radius());
+    let (stroke_width, circumference) = (config.stroke_width(), config.circumference());
+    let [track, buffer_ring, bar] = config.rings().map(|(stroke, _)| stroke);
+
+    // the rings are kept (rather than rendered again) when the value
+    // changes, so that their offset transitions smoothly
+    let current = move || config.clone().value(value.get()).buffer(buffer.get());
+    let (class, value_now) = (current.clone(), current.clone());
+    let (buffer_offset, bar_offset) = (current.clone(), current);
+
+    view! {
+        style(dangerously_set_inner_html=rendered.css)
+        svg(
+            class=move || class().class(),
+            "role"="progressbar",
+            aria-label=label,
+            aria-valuemin="0",
+            aria-valuemax="100",
+            aria-valuenow=move || value_now().value_now(),
+            width=size.to_string(),
+            height=size.to_string(),
+            "viewBox"=format!("0 0 {size} {size}"),
+        ) {
+            circle(
+                cx=center.to_string(),
+                cy=center.to_string(),
+                r=radius.to_string(),
+                stroke=track,
+                stroke-width=stroke_width.to_string(),
+            )
+            circle(
+                cx=center.to_string(),
+                cy=center.to_string(),
+                r=radius.to_string(),
+                stroke=buffer_ring,
+                stroke-width=stroke_width.to_string(),
+                stroke-dasharray=circumference.to_string(),
+                stroke-dashoffset=move || buffer_offset().buffer_offset(),
+            )
+            circle(
+                cx=center.to_string(),
+                cy=center.to_string(),
+                r=radius.to_string(),
+                stroke=bar,
+                stroke-width=stroke_width.to_string(),
+                stroke-dasharray=circumference.to_string(),
+                stroke-dashoffset=move || bar_offset().bar_offset(),
+            )
+        }
+    }
+}
@@ -0,0 +1,133 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! The [`skeletons`](crate::skeletons) as Sycamore components.
+
+use super::render_component;
+use crate::skeletons::{
+    SkeletonAnimation, SkeletonAvatarConfig, SkeletonCardConfig, SkeletonRectConfig,
+    SkeletonTableConfig, SkeletonTextConfig,
+};
+use sycamore::prelude::*;
+
+/// The props of [`SkeletonRect`], which are the same as with Leptos.
+#[derive(Props)]
+pub struct SkeletonRectProps {
+    #[prop(setter(into))]
+    pub color: String,
+    pub height: u32,
+    #[prop(default, setter(strip_option))]
+    pub width: Option<u32>,
+    #[prop(default, setter(strip_option, into))]
+    pub highlight: Option<String>,
+    #[prop(default)]
+    pub animation: SkeletonAnimation,
+}
+
+/// Renders a rectangular placeholder, see `spux::skeletons::SkeletonRect`.
+#[component]
+pub fn SkeletonRect(props: SkeletonRectProps) -> View {
+    let mut config = SkeletonRectConfig::new(props.color, props.height)
+        .width(props.width)
+        .animation(props.animation);
+    if let Some(highlight) = props.highlight {
+        config = config.highlight(highlight);
+    }
+    render_component(config)
+}
+
+/// The props of [`SkeletonAvatar`] and [`SkeletonCard`], which are the same
+/// as with Leptos.
+#[derive(Props)]
+pub struct SkeletonProps {
+    #[prop(setter(into))]
+    pub color: String,
+    pub size: u32,
+    #[prop(default, setter(strip_option, into))]
+    pub highlight: Option<String>,
+    #[prop(default)]
+    pub animation: SkeletonAnimation,
+}
+
+/// Renders a circular placeholder, see `spux::skeletons::SkeletonAvatar`.
+#[component]
+pub fn SkeletonAvatar(props: SkeletonProps) -> View {
+    let mut config = SkeletonAvatarConfig::new(props.color, props.size).animation(props.animation);
+    if let Some(highlight) = props.highlight {
+        config = config.highlight(highlight);
+    }
+    render_component(config)
+}
+
+/// Renders a card shaped placeholder, see `spux::skeletons::SkeletonCard`.
+#[component]
+pub fn SkeletonCard(props: SkeletonProps) -> View {
+    let mut config = SkeletonCardConfig::new(props.color, props.size).animation(props.animation);
+    if let Some(highlight) = props.highlight {
+        config = config.highlight(highlight);
+    }
+    render_component(config)
+}
+
+/// The props of [`SkeletonText`], which are the same as with Leptos.
+#[derive(Props)]
+pub struct SkeletonTextProps {
+    #[prop(setter(into))]
+    pub color: String,
+    pub size: u32,
+    #[prop(default = 3)]
+    pub lines: u32,
+    #[prop(default = 60)]
+    pub last_line_width: u32,
+    #[prop(default, setter(strip_option, into))]
+    pub highlight: Option<String>,
+    #[prop(default)]
+    pub animation: SkeletonAnimation,
+}
+
+/// Renders a paragraph shaped placeholder, see
+/// `spux::skeletons::SkeletonText`.
+#[component]
+pub fn SkeletonText(props: SkeletonTextProps) -> View {
+    let mut config = SkeletonTextConfig::new(props.color, props.size)
+        .lines(props.lines)
+        .last_line_width(props.last_line_width)
+        .animation(props.animation);
+    if let Some(highlight) = props.highlight {
+        config = config.highlight(highlight);
+    }
+    render_component(config)
+}
+
+/// The props of [`SkeletonTable`], which are the same as with Leptos.
+#[derive(Props)]
+pub struct SkeletonTableProps {
+    #[prop(setter(into))]
+    pub color: String,
+    pub size: u32,
+    #[prop(default = 5)]
+    pub rows: u32,
+    #[prop(default = 4)]
+    pub cols: u32,
+    #[prop(default, setter(strip_option, into))]
+    pub highlight: Option<String>,
+    #[prop(default)]
+    pub animation: SkeletonAnimation,
+}
+
+/// Renders a table shaped placeholder, see `spux::skeletons::SkeletonTable`.
+#[component]
+pub fn SkeletonTable(props: SkeletonTableProps) -> View {
+    let mut config = SkeletonTableConfig::new(props.color, props.size)
+        .rows(props.rows)
+        .cols(props.cols)
+        .animation(props.animation);
+    if let Some(highlight) = props.highlight {
+        config = config.highlight(highlight);
+    }
+    render_component(config)
+}
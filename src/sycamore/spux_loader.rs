@@ -0,0 +1,93 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+use crate::loader::LoaderDefinition;
+use crate::private::{loader_config, render_config};
+use crate::size::Size;
+use crate::svg::Renderer;
+use sycamore::prelude::*;
+
+/// The props of every Spux loader, which are the same as with Leptos: a
+/// required `size` (by px, or by em to render it inline), an optional
+/// `color` (#hex) that defaults to the color of the surrounding text, an
+/// optional `duration` (by ms), an optional `label` (defaults to "Loading")
+/// and an optional `renderer`.
+#[derive(Props)]
+pub struct LoaderProps {
+    #[prop(default, setter(strip_option, into))]
+    pub color: Option<String>,
+    #[prop(setter(into))]
+    pub size: Size,
+    #[prop(default, setter(strip_option))]
+    pub duration: Option<u32>,
+    #[prop(default = "Loading".to_string(), setter(into))]
+    pub label: String,
+    #[prop(default, setter(strip_option))]
+    pub renderer: Option<Renderer>,
+}
+
+/// The props of [`SpuxLoader`], see [`LoaderProps`].
+#[derive(Props)]
+pub struct SpuxLoaderProps {
+    pub definition: LoaderDefinition,
+    #[prop(default, setter(strip_option, into))]
+    pub color: Option<String>,
+    #[prop(setter(into))]
+    pub size: Size,
+    #[prop(default, setter(strip_option))]
+    pub duration: Option<u32>,
+    #[prop(default = "Loading".to_string(), setter(into))]
+    pub label: String,
+    #[prop(default, setter(strip_option))]
+    pub renderer: Option<Renderer>,
+}
+
+/// Renders any loader from its definition, e.g a custom loader declared
+/// with `spux_loader!` (and named after a function).
+#[component]
+pub fn SpuxLoader(props: SpuxLoaderProps) -> View {
+    let SpuxLoaderProps {
+        definition,
+        color,
+        size,
+        duration,
+        label,
+        renderer,
+    } = props;
+    render(
+        definition,
+        LoaderProps {
+            color,
+            size,
+            duration,
+            label,
+            renderer,
+        },
+    )
+}
+
+// renders a loader from the markup and CSS that `core::render` renders it into
+pub(crate) fn render(definition: LoaderDefinition, props: LoaderProps) -> View {
+    let rendered = render_config(loader_config(
+        definition,
+        props.color.as_deref(),
+        props.size,
+        props.duration,
+        &props.label,
+        props.renderer,
+    ));
+
+    view! {
+        style(dangerously_set_inner_html=rendered.css)
+        span(
+            class="spux-loader",
+            role="status",
+            aria-label=props.label,
+            style=rendered.style,
+            dangerously_set_inner_html=rendered.content,
+        )
+    }
+}
@@ -0,0 +1,133 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! The [`text`](crate::text) loaders as Sycamore components.
+
+use super::render_component;
+use crate::private::render_config;
+use crate::text::{EllipsisConfig, ScrambleConfig, ShimmerTextConfig, TypewriterConfig};
+use gloo_timers::callback::Interval;
+use std::time::Duration;
+use sycamore::prelude::*;
+
+/// The props of the text loaders, which are the same as with Leptos: an
+/// optional `color` (#hex) that defaults to the color of the surrounding
+/// text, an optional `text` (defaults to "Loading") and an optional
+/// `duration` (by ms, defaults to 1400).
+#[derive(Props)]
+pub struct TextProps {
+    #[prop(default, setter(strip_option, into))]
+    pub color: Option<String>,
+    #[prop(default = "Loading".to_string(), setter(into))]
+    pub text: String,
+    #[prop(default = 1400)]
+    pub duration: u32,
+}
+
+/// The props of [`ShimmerText`], see [`TextProps`]. The `highlight` color
+/// defaults to a lighter shade of `color`.
+#[derive(Props)]
+pub struct ShimmerTextProps {
+    #[prop(default, setter(strip_option, into))]
+    pub color: Option<String>,
+    #[prop(default = "Loading".to_string(), setter(into))]
+    pub text: String,
+    #[prop(default, setter(strip_option, into))]
+    pub highlight: Option<String>,
+    #[prop(default = 1400)]
+    pub duration: u32,
+}
+
+/// Renders a piece of text followed by three dots that appear one after the
+/// other, see `spux::text::Ellipsis`.
+#[component]
+pub fn Ellipsis(props: TextProps) -> View {
+    let mut config = EllipsisConfig::new()
+        .text(props.text)
+        .duration(props.duration);
+    if let Some(color) = props.color {
+        config = config.color(color);
+    }
+    render_component(config)
+}
+
+/// Renders a piece of text with a highlight sweeping across it, see
+/// `spux::text::ShimmerText`.
+#[component]
+pub fn ShimmerText(props: ShimmerTextProps) -> View {
+    let mut config = ShimmerTextConfig::new()
+        .text(props.text)
+        .duration(props.duration);
+    if let Some(color) = props.color {
+        config = config.color(color);
+    }
+    if let Some(highlight) = props.highlight {
+        config = config.highlight(highlight);
+    }
+    render_component(config)
+}
+
+/// Renders a piece of text that is typed out character by character behind
+/// a blinking caret, see `spux::text::Typewriter`.
+#[component]
+pub fn Typewriter(props: TextProps) -> View {
+    let mut config = TypewriterConfig::new()
+        .text(props.text.clone())
+        .duration(props.duration);
+    if let Some(color) = props.color {
+        config = config.color(color);
+    }
+    let tick = use_tick(config.ticks());
+    let rendered = render_config(config.clone());
+
+    view! {
+        style(dangerously_set_inner_html=rendered.css)
+        span(class="spux-typewriter", role="status", style=rendered.style) {
+            span(class="spux-visually-hidden") { (props.text) }
+            span(aria-hidden="true") { (move || config.frame(tick.get())) }
+            span(class="spux-typewriter-caret", aria-hidden="true")
+        }
+    }
+}
+
+/// Renders a piece of text as scrambled glyphs that resolve into the final
+/// word from left to right, see `spux::text::Scramble`.
+#[component]
+pub fn Scramble(props: TextProps) -> View {
+    let mut config = ScrambleConfig::new()
+        .text(props.text.clone())
+        .duration(props.duration);
+    if let Some(color) = props.color {
+        config = config.color(color);
+    }
+    let tick = use_tick(config.ticks());
+    let rendered = render_config(config.clone());
+
+    view! {
+        style(dangerously_set_inner_html=rendered.css)
+        span(class="spux-scramble", role="status", style=rendered.style) {
+            span(class="spux-visually-hidden") { (props.text) }
+            span(aria-hidden="true") { (move || config.frame(tick.get())) }
+        }
+    }
+}
+
+// returns a signal that counts the ticks of a text loader from 0 (and then
+// wraps). components are only mounted in the browser, so server rendered
+// text always starts from the first tick
+fn use_tick((ticks, interval): (usize, Duration)) -> ReadSignal<usize> {
+    let tick = create_signal(0);
+
+    on_mount(move || {
+        let handle = Interval::new(interval.as_millis() as u32, move || {
+            tick.set((tick.get() + 1) % ticks);
+        });
+        on_cleanup(move || drop(handle));
+    });
+
+    *tick
+}
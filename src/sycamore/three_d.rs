@@ -0,0 +1,86 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! The [`three_d`](crate::three_d) loaders as Sycamore components.
+
+use super::render_component;
+use crate::size::Size;
+use crate::three_d::{CardFlipConfig, CubeConfig, FlipSquareConfig};
+use sycamore::prelude::*;
+
+/// The props of [`FlipSquare`], which are the same as with Leptos.
+#[derive(Props)]
+pub struct FlipSquareProps {
+    #[prop(default, setter(strip_option, into))]
+    pub color: Option<String>,
+    #[prop(setter(into))]
+    pub size: Size,
+    #[prop(default)]
+    pub filled: bool,
+    #[prop(default = "Loading".to_string(), setter(into))]
+    pub label: String,
+}
+
+/// The props of [`Cube`], which are the same as with Leptos.
+#[derive(Props)]
+pub struct CubeProps {
+    #[prop(default, setter(strip_option, into))]
+    pub color: Option<String>,
+    #[prop(setter(into))]
+    pub size: Size,
+    #[prop(default, setter(strip_option))]
+    pub faces: Option<[&'static str; 6]>,
+    #[prop(default = "Loading".to_string(), setter(into))]
+    pub label: String,
+}
+
+/// The props of [`CardFlip`], which are the same as with Leptos.
+#[derive(Props)]
+pub struct CardFlipProps {
+    #[prop(default, setter(strip_option, into))]
+    pub color: Option<String>,
+    #[prop(setter(into))]
+    pub size: Size,
+    #[prop(default = "Loading".to_string(), setter(into))]
+    pub label: String,
+}
+
+/// Renders a square that flips around its X axis and then around its Y axis
+/// in turn, see `spux::three_d::FlipSquare`.
+#[component]
+pub fn FlipSquare(props: FlipSquareProps) -> View {
+    let mut config = FlipSquareConfig::new(props.size)
+        .filled(props.filled)
+        .label(props.label);
+    if let Some(color) = props.color {
+        config = config.color(color);
+    }
+    render_component(config)
+}
+
+/// Renders a rotating cube, see `spux::three_d::Cube`.
+#[component]
+pub fn Cube(props: CubeProps) -> View {
+    let mut config = CubeConfig::new(props.size).label(props.label);
+    if let Some(color) = props.color {
+        config = config.color(color);
+    }
+    if let Some(faces) = props.faces {
+        config = config.faces(faces);
+    }
+    render_component(config)
+}
+
+/// Renders a card that keeps flipping over, see `spux::three_d::CardFlip`.
+#[component]
+pub fn CardFlip(props: CardFlipProps) -> View {
+    let mut config = CardFlipConfig::new(props.size).label(props.label);
+    if let Some(color) = props.color {
+        config = config.color(color);
+    }
+    render_component(config)
+}
@@ -0,0 +1,231 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+/*!
+ Rendering loaders in a terminal.
+
+ Every built-in loader has a sequence of Unicode glyphs (e.g `◰◳◲◱` for the
+ `Square` spinner and `·•●•` for the `Circle` pulser) that a
+ [`TerminalLoader`] cycles through over the duration of the loader, colored
+ with 24-bit ANSI colors. A loader can be spun on stdout from a background
+ thread with [`TerminalLoader::spawn`], or drawn in a `ratatui` interface as
+ a `StatefulWidget`.
+
+```
+use spux::loader::LoaderDefinition;
+use spux::terminal::TerminalLoader;
+use std::time::Duration;
+
+let dots = LoaderDefinition::new("brand-dots", "", "").glyphs(&["·", "•", "●", "•"]);
+let loader = TerminalLoader::new(dots).duration(800).label("Saving");
+
+assert_eq!(loader.frame_interval(), Duration::from_millis(200));
+assert_eq!(loader.frame_at(Duration::from_millis(450)), "●");
+assert_eq!(loader.line(Duration::ZERO), "· Saving");
+assert!(loader.color("black").is_err());
+```
+
+ With `ratatui`, the state of the widget keeps track of when the loader
+ started, so it only has to be redrawn (e.g every
+ [`frame_interval`](TerminalLoader::frame_interval)) to animate:
+
+```rust,ignore
+use spux::terminal::{TerminalLoader, TerminalLoaderState};
+
+let loader = TerminalLoader::new(spux::spinners::square_definition()).color("#112233")?;
+let mut state = TerminalLoaderState::default();
+
+terminal.draw(|frame| frame.render_stateful_widget(&loader, frame.area(), &mut state))?;
+```
+*/
+use crate::errors::ValidationError;
+use crate::loader::LoaderDefinition;
+use crate::types::rgb;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::widgets::StatefulWidget;
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+// the glyphs of loaders that don't have any of their own
+const DEFAULT_GLYPHS: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// A loader in a terminal, see the [module documentation](self).
+///
+/// The `color` (#hex) defaults to the color of the surrounding text, the
+/// `duration` (by ms) of one cycle through the glyphs to the duration of the
+/// loader and the `label` (printed after the glyph) to none.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TerminalLoader {
+    glyphs: &'static [&'static str],
+    color: Option<(u8, u8, u8)>,
+    duration: u32,
+    label: String,
+}
+
+impl TerminalLoader {
+    /// The loader with the glyphs of its definition, or with a braille
+    /// spinner if it doesn't have any.
+    pub fn new(definition: LoaderDefinition) -> Self {
+        TerminalLoader {
+            glyphs: definition.terminal_glyphs().unwrap_or(DEFAULT_GLYPHS),
+            color: None,
+            duration: definition.default_duration(),
+            label: String::new(),
+        }
+    }
+
+    pub fn glyphs(self, glyphs: &'static [&'static str]) -> Self {
+        TerminalLoader { glyphs, ..self }
+    }
+
+    /// Colors the glyphs, failing when `color` isn't a #hex color code.
+    pub fn color(self, color: &str) -> Result<Self, ValidationError> {
+        Ok(TerminalLoader {
+            color: Some(rgb(color)?),
+            ..self
+        })
+    }
+
+    pub fn duration(self, duration: u32) -> Self {
+        TerminalLoader { duration, ..self }
+    }
+
+    pub fn label(self, label: impl Into<String>) -> Self {
+        TerminalLoader {
+            label: label.into(),
+            ..self
+        }
+    }
+
+    /// How long each glyph is shown for.
+    pub fn frame_interval(&self) -> Duration {
+        let frames = self.glyphs.len().max(1) as u32;
+        Duration::from_millis(self.duration.max(1) as u64) / frames
+    }
+
+    /// The glyph shown once the loader has run for `elapsed`.
+    pub fn frame_at(&self, elapsed: Duration) -> &'static str {
+        if self.glyphs.is_empty() {
+            return "";
+        }
+        let interval = self.frame_interval().as_nanos().max(1);
+        self.glyphs[(elapsed.as_nanos() / interval) as usize % self.glyphs.len()]
+    }
+
+    /// The line printed once the loader has run for `elapsed`, with the
+    /// glyph colored by an ANSI escape code (if the loader has a color).
+    pub fn line(&self, elapsed: Duration) -> String {
+        let glyph = match self.color {
+            Some((r, g, b)) => format!("\x1b[38;2;{r};{g};{b}m{}\x1b[0m", self.frame_at(elapsed)),
+            None => self.frame_at(elapsed).to_string(),
+        };
+        if self.label.is_empty() {
+            glyph
+        } else {
+            format!("{glyph} {}", self.label)
+        }
+    }
+
+    /// Spins the loader on stdout from a background thread, until the
+    /// returned [`Ticker`] is stopped (or dropped).
+    pub fn spawn(self) -> Ticker {
+        let stopped = Arc::new(AtomicBool::new(false));
+        let handle = thread::spawn({
+            let stopped = stopped.clone();
+            move || {
+                let started = Instant::now();
+                let mut stdout = io::stdout();
+                while !stopped.load(Ordering::Relaxed) {
+                    let _ = write!(stdout, "\r\x1b[2K{}", self.line(started.elapsed()));
+                    let _ = stdout.flush();
+                    thread::park_timeout(self.frame_interval());
+                }
+                let _ = write!(stdout, "\r\x1b[2K");
+                let _ = stdout.flush();
+            }
+        });
+        Ticker {
+            stopped,
+            handle: Some(handle),
+        }
+    }
+}
+
+/// A loader spinning on stdout, see [`TerminalLoader::spawn`].
+#[derive(Debug)]
+pub struct Ticker {
+    stopped: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Ticker {
+    /// Stops the loader and clears its line.
+    pub fn stop(self) {
+        drop(self);
+    }
+}
+
+impl Drop for Ticker {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            handle.thread().unpark();
+            let _ = handle.join();
+        }
+    }
+}
+
+/// The state of a [`TerminalLoader`] drawn with `ratatui`, which is when the
+/// loader started.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TerminalLoaderState {
+    started: Instant,
+}
+
+impl TerminalLoaderState {
+    pub fn new() -> Self {
+        TerminalLoaderState {
+            started: Instant::now(),
+        }
+    }
+
+    /// Starts the loader over from its first glyph.
+    pub fn restart(&mut self) {
+        self.started = Instant::now();
+    }
+}
+
+impl Default for TerminalLoaderState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StatefulWidget for &TerminalLoader {
+    type State = TerminalLoaderState;
+
+    fn render(self, area: Rect, buffer: &mut Buffer, state: &mut Self::State) {
+        if area.is_empty() {
+            return;
+        }
+        let glyph = self.frame_at(state.started.elapsed());
+        let style = match self.color {
+            Some((r, g, b)) => Style::new().fg(Color::Rgb(r, g, b)),
+            None => Style::new(),
+        };
+        let (x, _) = buffer.set_stringn(area.x, area.y, glyph, area.width as usize, style);
+        if !self.label.is_empty() {
+            let width = area.right().saturating_sub(x) as usize;
+            buffer.set_stringn(x, area.y, format!(" {}", self.label), width, Style::new());
+        }
+    }
+}
@@ -0,0 +1,87 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+#[cfg(feature = "leptos")]
+use crate::private::is_interactive;
+#[cfg(feature = "leptos")]
+use leptos::prelude::*;
+use std::time::Duration;
+
+mod ellipsis;
+#[cfg(feature = "leptos")]
+pub use ellipsis::Ellipsis;
+pub use ellipsis::EllipsisConfig;
+
+mod shimmer_text;
+#[cfg(feature = "leptos")]
+pub use shimmer_text::ShimmerText;
+pub use shimmer_text::ShimmerTextConfig;
+
+mod typewriter;
+#[cfg(feature = "leptos")]
+pub use typewriter::Typewriter;
+pub use typewriter::TypewriterConfig;
+
+mod scramble;
+#[cfg(feature = "leptos")]
+pub use scramble::Scramble;
+pub use scramble::ScrambleConfig;
+
+// the animated copies of the text are hidden from assistive technologies, and this
+// visually hidden copy is announced instead so the text is only ever read out once
+const VISUALLY_HIDDEN_STYLE: &str = "
+    .spux-visually-hidden {
+        position: absolute;
+        width: 1px;
+        height: 1px;
+        padding: 0px;
+        margin: -1px;
+        overflow: hidden;
+        clip: rect(0, 0, 0, 0);
+        white-space: nowrap;
+        border: 0px;
+    }
+";
+
+// the number of extra ticks where the fully revealed text is held before
+// the animation starts over again
+const HOLD_TICKS: usize = 3;
+
+// the number of ticks in one cycle of the animation, and the time between
+// two of them
+fn ticks(duration: u32, steps: usize) -> (usize, Duration) {
+    let ticks = steps + HOLD_TICKS;
+    let interval = Duration::from_millis((duration as usize / ticks).max(1) as u64);
+    (ticks, interval)
+}
+
+// returns a signal that counts from 0 up to `steps + HOLD_TICKS` (and then wraps)
+// over the given duration. the interval is only started in the browser so
+// server rendered text always starts from the first tick
+#[cfg(feature = "leptos")]
+fn use_tick(duration: u32, steps: usize) -> ReadSignal<usize> {
+    // in islands mode, text outside of an island never runs in the browser,
+    // so it's rendered fully revealed rather than stuck on its first tick
+    if !is_interactive() {
+        return signal(steps).0;
+    }
+
+    let (tick, set_tick) = signal(0);
+    let (ticks, interval) = ticks(duration, steps);
+
+    Effect::new(move |_| {
+        let handle = set_interval_with_handle(
+            move || set_tick.update(|tick| *tick = (*tick + 1) % ticks),
+            interval,
+        );
+
+        if let Ok(handle) = handle {
+            on_cleanup(move || handle.clear());
+        }
+    });
+
+    tick
+}
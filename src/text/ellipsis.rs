@@ -0,0 +1,193 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+use crate::core::{element, escape, loader_color, Render, RenderedLoader};
+use crate::errors::ValidationError;
+#[cfg(feature = "leptos")]
+use crate::private::{loader_style, render_config};
+#[cfg(feature = "leptos")]
+use leptos::prelude::*;
+
+const STYLE: &str = "
+    .spux-ellipsis {
+        font: inherit;
+        white-space: nowrap;
+    }
+
+    .spux-ellipsis-dot {
+        opacity: 0;
+        animation-timing-function: step-end;
+        animation-iteration-count: infinite;
+    }
+
+    .spux-ellipsis-dot:nth-child(1) {
+        animation-name: spux-ellipsis-1;
+    }
+
+    .spux-ellipsis-dot:nth-child(2) {
+        animation-name: spux-ellipsis-2;
+    }
+
+    .spux-ellipsis-dot:nth-child(3) {
+        animation-name: spux-ellipsis-3;
+    }
+
+    @keyframes spux-ellipsis-1 {
+        0% {
+            opacity: 0;
+        }
+        25% {
+            opacity: 1;
+        }
+    }
+
+    @keyframes spux-ellipsis-2 {
+        0% {
+            opacity: 0;
+        }
+        50% {
+            opacity: 1;
+        }
+    }
+
+    @keyframes spux-ellipsis-3 {
+        0% {
+            opacity: 0;
+        }
+        75% {
+            opacity: 1;
+        }
+    }
+";
+
+/// A piece of text followed by three dots, as rendered by the `Ellipsis`
+/// component, see the [`core`](crate::core) module.
+///
+/// The optional color (#hex) defaults to the color of the surrounding text,
+/// while `text` defaults to "Loading" and `duration` (by ms) defaults to 1400.
+///
+/// # Example Usage
+/// ```
+/// use spux::core::render;
+/// use spux::text::EllipsisConfig;
+///
+/// let rendered = render(EllipsisConfig::new().text("Saving")).unwrap();
+/// assert!(rendered.html.contains("aria-label=\"Saving\""));
+/// assert_eq!(rendered.html.matches("class=\"spux-ellipsis-dot\"").count(), 3);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct EllipsisConfig {
+    color: Option<String>,
+    text: String,
+    duration: u32,
+}
+
+impl Default for EllipsisConfig {
+    fn default() -> Self {
+        EllipsisConfig::new()
+    }
+}
+
+impl EllipsisConfig {
+    pub fn new() -> Self {
+        EllipsisConfig {
+            color: None,
+            text: "Loading".to_string(),
+            duration: 1400,
+        }
+    }
+
+    pub fn color(self, color: impl Into<String>) -> Self {
+        EllipsisConfig {
+            color: Some(color.into()),
+            ..self
+        }
+    }
+
+    pub fn text(self, text: impl Into<String>) -> Self {
+        EllipsisConfig {
+            text: text.into(),
+            ..self
+        }
+    }
+
+    pub fn duration(self, duration: u32) -> Self {
+        EllipsisConfig { duration, ..self }
+    }
+}
+
+impl Render for EllipsisConfig {
+    fn render(self) -> Result<RenderedLoader, ValidationError> {
+        let color = loader_color(self.color.as_deref())?;
+
+        let dot = element(
+            "span",
+            &[
+                ("class", "spux-ellipsis-dot"),
+                ("style", &format!("animation-duration: {}ms", self.duration)),
+            ],
+            ".",
+        );
+        let content = element("span", &[("aria-hidden", "true")], &escape(&self.text))
+            + &element("span", &[("aria-hidden", "true")], &dot.repeat(3));
+
+        Ok(RenderedLoader::new(
+            "span",
+            &[
+                ("class", "spux-ellipsis"),
+                ("role", "status"),
+                ("aria-label", &self.text),
+            ],
+            format!("color: {color}"),
+            content,
+            STYLE,
+            &["spux-ellipsis", "spux-ellipsis-dot"],
+        ))
+    }
+}
+
+/// Renders a piece of text followed by three dots that appear one after
+/// the other, e.g "Loading...".
+///
+/// Takes in an optional color (#hex), which defaults to the color of the
+/// surrounding text, while `text` defaults to "Loading" and `duration` (by
+/// ms) defaults to 1400. The font is inherited from the surrounding text.
+///
+/// # Example Usage
+/// ```
+/// use leptos::prelude::*;
+/// use spux::text::Ellipsis;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <p>"Please wait, " <Ellipsis color="#000000" text="fetching posts" /></p>
+///     }
+/// }
+/// ```
+#[cfg(feature = "leptos")]
+#[component]
+pub fn Ellipsis(
+    #[prop(default = None, into)] color: Option<&'static str>,
+    #[prop(default = "Loading")] text: &'static str,
+    #[prop(default = 1400)] duration: u32,
+) -> impl IntoView {
+    let mut config = EllipsisConfig::new().text(text).duration(duration);
+    if let Some(color) = color {
+        config = config.color(color);
+    }
+    let rendered = render_config(config);
+
+    view! {
+        {loader_style(rendered.css)}
+        <span class="spux-ellipsis"
+            role="status"
+            aria-label=text
+            style=rendered.style
+            inner_html=rendered.content
+        ></span>
+    }
+}
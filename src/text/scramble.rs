@@ -0,0 +1,196 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+#[cfg(feature = "leptos")]
+use super::use_tick;
+use super::{ticks, VISUALLY_HIDDEN_STYLE};
+use crate::core::{element, escape, loader_color, Render, RenderedLoader};
+use crate::errors::ValidationError;
+#[cfg(feature = "leptos")]
+use crate::private::{loader_style, render_config};
+#[cfg(feature = "leptos")]
+use leptos::prelude::*;
+use std::time::Duration;
+
+const GLYPHS: &[char] = &[
+    'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'K', 'M', 'N', 'P', 'R', 'S', 'T', 'X', 'Z', '0', '1',
+    '2', '3', '4', '5', '7', '8', '#', '%', '&', '*', '+', '?', '/',
+];
+
+const STYLE: &str = "
+    .spux-scramble {
+        font: inherit;
+        white-space: pre;
+    }
+";
+
+/// A piece of text as scrambled glyphs that resolve into the final word, as
+/// rendered by the `Scramble` component, see the [`core`](crate::core) module.
+///
+/// The optional color (#hex) defaults to the color of the surrounding text,
+/// while `text` defaults to "Loading" and `duration` (by ms, for one full
+/// cycle) defaults to 1400. The text resolves by ticks (see
+/// [`ticks`](Self::ticks)), and is rendered fully resolved unless it's given
+/// the `tick` to render.
+///
+/// # Example Usage
+/// ```
+/// use spux::core::render;
+/// use spux::text::ScrambleConfig;
+///
+/// let rendered = render(ScrambleConfig::new().text("Decrypting")).unwrap();
+/// assert!(rendered.html.contains("<span aria-hidden=\"true\">Decrypting</span>"));
+///
+/// let rendered = render(ScrambleConfig::new().text("Decrypting").tick(3)).unwrap();
+/// assert!(rendered.html.contains("<span aria-hidden=\"true\">Dec"));
+/// assert!(!rendered.html.contains("<span aria-hidden=\"true\">Decrypting</span>"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScrambleConfig {
+    color: Option<String>,
+    text: String,
+    duration: u32,
+    tick: Option<usize>,
+}
+
+impl Default for ScrambleConfig {
+    fn default() -> Self {
+        ScrambleConfig::new()
+    }
+}
+
+impl ScrambleConfig {
+    pub fn new() -> Self {
+        ScrambleConfig {
+            color: None,
+            text: "Loading".to_string(),
+            duration: 1400,
+            tick: None,
+        }
+    }
+
+    pub fn color(self, color: impl Into<String>) -> Self {
+        ScrambleConfig {
+            color: Some(color.into()),
+            ..self
+        }
+    }
+
+    pub fn text(self, text: impl Into<String>) -> Self {
+        ScrambleConfig {
+            text: text.into(),
+            ..self
+        }
+    }
+
+    pub fn duration(self, duration: u32) -> Self {
+        ScrambleConfig { duration, ..self }
+    }
+
+    /// Renders the text as it is resolved at a tick of the animation.
+    pub fn tick(self, tick: usize) -> Self {
+        ScrambleConfig {
+            tick: Some(tick),
+            ..self
+        }
+    }
+
+    /// The number of ticks in one cycle of the animation (one per character,
+    /// and a few more for holding the resolved text), and the time between
+    /// two of them.
+    pub fn ticks(&self) -> (usize, Duration) {
+        ticks(self.duration, self.text.chars().count())
+    }
+
+    // characters before the tick are resolved, the rest are replaced with
+    // glyphs picked by a small hash of the tick and the character index.
+    // this keeps the output deterministic between the server and the browser
+    pub(crate) fn frame(&self, tick: usize) -> String {
+        self.text
+            .chars()
+            .enumerate()
+            .map(|(index, char)| {
+                if index < tick || char.is_whitespace() {
+                    return char;
+                }
+                let mut hash = (tick as u32 + 1).wrapping_mul(0x9e37_79b1)
+                    ^ (index as u32 + 1).wrapping_mul(0x85eb_ca77);
+                hash ^= hash >> 15;
+                hash = hash.wrapping_mul(0x2c1b_3c6d);
+                hash ^= hash >> 12;
+                GLYPHS[hash as usize % GLYPHS.len()]
+            })
+            .collect()
+    }
+}
+
+impl Render for ScrambleConfig {
+    fn render(self) -> Result<RenderedLoader, ValidationError> {
+        let color = loader_color(self.color.as_deref())?;
+
+        let scrambled = self.frame(self.tick.unwrap_or(usize::MAX));
+        let content = element(
+            "span",
+            &[("class", "spux-visually-hidden")],
+            &escape(&self.text),
+        ) + &element("span", &[("aria-hidden", "true")], &escape(&scrambled));
+
+        Ok(RenderedLoader::new(
+            "span",
+            &[("class", "spux-scramble"), ("role", "status")],
+            format!("color: {color}"),
+            content,
+            &format!("{VISUALLY_HIDDEN_STYLE}{STYLE}"),
+            &["spux-scramble", "spux-visually-hidden"],
+        ))
+    }
+}
+
+/// Renders a piece of text as scrambled glyphs that resolve into the
+/// final word from left to right, and then starts over again.
+///
+/// Takes in an optional color (#hex), which defaults to the color of the
+/// surrounding text, while `text` defaults to "Loading" and `duration` (by
+/// ms, for one full cycle) defaults to 1400. The font is inherited from the
+/// surrounding text.
+///
+/// # Example Usage
+/// ```
+/// use leptos::prelude::*;
+/// use spux::text::Scramble;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <code><Scramble color="#000000" text="Decrypting" /></code>
+///     }
+/// }
+/// ```
+#[cfg(feature = "leptos")]
+#[component]
+pub fn Scramble(
+    #[prop(default = None, into)] color: Option<&'static str>,
+    #[prop(default = "Loading")] text: &'static str,
+    #[prop(default = 1400)] duration: u32,
+) -> impl IntoView {
+    let mut config = ScrambleConfig::new().text(text).duration(duration);
+    if let Some(color) = color {
+        config = config.color(color);
+    }
+    let rendered = render_config(config.clone());
+
+    let tick = use_tick(duration, text.chars().count());
+    let config = StoredValue::new(config);
+    let scrambled = move || config.with_value(|config| config.frame(tick.get()));
+
+    view! {
+        {loader_style(rendered.css)}
+        <span class="spux-scramble" role="status" style=rendered.style>
+            <span class="spux-visually-hidden">{text}</span>
+            <span aria-hidden="true">{scrambled}</span>
+        </span>
+    }
+}
@@ -0,0 +1,185 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+use crate::core::{escape, loader_color, Render, RenderedLoader};
+use crate::errors::ValidationError;
+#[cfg(feature = "leptos")]
+use crate::private::{loader_style, render_config};
+use crate::types::{Shading, Validation};
+#[cfg(feature = "leptos")]
+use leptos::prelude::*;
+
+const STYLE: &str = "
+    .spux-shimmer-text {
+        font: inherit;
+        -webkit-text-fill-color: transparent;
+        background-size: 200% 100%;
+        -webkit-background-clip: text;
+        background-clip: text;
+        animation-name: spux-shimmer-text;
+        animation-timing-function: linear;
+        animation-iteration-count: infinite;
+    }
+
+    @keyframes spux-shimmer-text {
+        0% {
+            background-position: 100% 0px;
+        }
+        100% {
+            background-position: -100% 0px;
+        }
+    }
+";
+
+/// A piece of text with a highlight sweeping across it, as rendered by the
+/// `ShimmerText` component, see the [`core`](crate::core) module.
+///
+/// The optional color (#hex) defaults to the color of the surrounding text,
+/// while `text` defaults to "Loading", `duration` (by ms) defaults to 1400
+/// and the `highlight` color defaults to a lighter shade of `color`.
+///
+/// # Example Usage
+/// ```
+/// use spux::core::render;
+/// use spux::text::ShimmerTextConfig;
+///
+/// let rendered = render(ShimmerTextConfig::new().color("#000000")).unwrap();
+/// assert!(rendered.html.contains("#999999 50%"));
+/// assert!(render(ShimmerTextConfig::new().highlight("white")).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShimmerTextConfig {
+    color: Option<String>,
+    text: String,
+    highlight: Option<String>,
+    duration: u32,
+}
+
+impl Default for ShimmerTextConfig {
+    fn default() -> Self {
+        ShimmerTextConfig::new()
+    }
+}
+
+impl ShimmerTextConfig {
+    pub fn new() -> Self {
+        ShimmerTextConfig {
+            color: None,
+            text: "Loading".to_string(),
+            highlight: None,
+            duration: 1400,
+        }
+    }
+
+    pub fn color(self, color: impl Into<String>) -> Self {
+        ShimmerTextConfig {
+            color: Some(color.into()),
+            ..self
+        }
+    }
+
+    pub fn text(self, text: impl Into<String>) -> Self {
+        ShimmerTextConfig {
+            text: text.into(),
+            ..self
+        }
+    }
+
+    pub fn highlight(self, highlight: impl Into<String>) -> Self {
+        ShimmerTextConfig {
+            highlight: Some(highlight.into()),
+            ..self
+        }
+    }
+
+    pub fn duration(self, duration: u32) -> Self {
+        ShimmerTextConfig { duration, ..self }
+    }
+}
+
+impl Render for ShimmerTextConfig {
+    fn render(self) -> Result<RenderedLoader, ValidationError> {
+        let color = loader_color(self.color.as_deref())?;
+
+        let highlight = match self.highlight.as_deref() {
+            Some(highlight) => {
+                highlight.validate_color_code().map_err(|_| {
+                    ValidationError::new(
+                        "Highlight should be in the #hex format (e.g #000000) for black",
+                    )
+                })?;
+                highlight.to_string()
+            }
+            // the color of the surrounding text is only known by the browser,
+            // which mixes it with white the same way `lighten` does
+            None if color == "currentColor" => {
+                "color-mix(in srgb, currentColor, #ffffff 60%)".to_string()
+            }
+            None => color.lighten(0.6),
+        };
+        let style = format!(
+            "background-image: linear-gradient(90deg, {color} 0%, {color} 35%, {highlight} 50%, {color} 65%, {color} 100%); \
+             animation-duration: {}ms",
+            self.duration
+        );
+
+        Ok(RenderedLoader::new(
+            "span",
+            &[("class", "spux-shimmer-text"), ("role", "status")],
+            style,
+            escape(&self.text),
+            STYLE,
+            &["spux-shimmer-text"],
+        ))
+    }
+}
+
+/// Renders a piece of text with a highlight sweeping across it.
+///
+/// Takes in an optional color (#hex), which defaults to the color of the
+/// surrounding text, while `text` defaults to "Loading" and `duration` (by
+/// ms) defaults to 1400. The `highlight` color defaults to a lighter shade
+/// of `color`. The font is inherited from the surrounding text.
+///
+/// # Example Usage
+/// ```
+/// use leptos::prelude::*;
+/// use spux::text::ShimmerText;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <h2><ShimmerText color="#000000" text="Preparing your report" /></h2>
+///     }
+/// }
+/// ```
+#[cfg(feature = "leptos")]
+#[component]
+pub fn ShimmerText(
+    #[prop(default = None, into)] color: Option<&'static str>,
+    #[prop(default = "Loading")] text: &'static str,
+    #[prop(default = None, into)] highlight: Option<&'static str>,
+    #[prop(default = 1400)] duration: u32,
+) -> impl IntoView {
+    let mut config = ShimmerTextConfig::new().text(text).duration(duration);
+    if let Some(color) = color {
+        config = config.color(color);
+    }
+    if let Some(highlight) = highlight {
+        config = config.highlight(highlight);
+    }
+    let rendered = render_config(config);
+
+    view! {
+        {loader_style(rendered.css)}
+        <span class="spux-shimmer-text"
+            role="status"
+            style=rendered.style
+        >
+            {text}
+        </span>
+    }
+}
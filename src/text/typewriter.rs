@@ -0,0 +1,199 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+#[cfg(feature = "leptos")]
+use super::use_tick;
+use super::{ticks, VISUALLY_HIDDEN_STYLE};
+use crate::core::{element, escape, loader_color, Render, RenderedLoader};
+use crate::errors::ValidationError;
+#[cfg(feature = "leptos")]
+use crate::private::{loader_style, render_config};
+#[cfg(feature = "leptos")]
+use leptos::prelude::*;
+use std::time::Duration;
+
+const STYLE: &str = "
+    .spux-typewriter {
+        font: inherit;
+        white-space: pre;
+    }
+
+    .spux-typewriter-caret {
+        border-right: 0.08em solid;
+        margin-left: 0.05em;
+        animation: spux-typewriter-caret 700ms step-end forwards;
+        animation-iteration-count: infinite;
+    }
+
+    @keyframes spux-typewriter-caret {
+        0% {
+            opacity: 1;
+        }
+        50% {
+            opacity: 0;
+        }
+    }
+";
+
+/// A piece of text that is typed out character by character, as rendered by
+/// the `Typewriter` component, see the [`core`](crate::core) module.
+///
+/// The optional color (#hex) defaults to the color of the surrounding text,
+/// while `text` defaults to "Loading" and `duration` (by ms, for one full
+/// cycle) defaults to 1400. The text is typed out by ticks (see
+/// [`ticks`](Self::ticks)), and is rendered fully typed out unless it's
+/// given the `tick` to render.
+///
+/// # Example Usage
+/// ```
+/// use spux::core::render;
+/// use spux::text::TypewriterConfig;
+///
+/// let typewriter = TypewriterConfig::new().text("Thinking");
+/// let rendered = render(typewriter.clone().tick(5)).unwrap();
+/// assert!(rendered.html.contains("<span aria-hidden=\"true\">Think</span>"));
+/// assert_eq!(typewriter.ticks().0, 11);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypewriterConfig {
+    color: Option<String>,
+    text: String,
+    duration: u32,
+    tick: Option<usize>,
+}
+
+impl Default for TypewriterConfig {
+    fn default() -> Self {
+        TypewriterConfig::new()
+    }
+}
+
+impl TypewriterConfig {
+    pub fn new() -> Self {
+        TypewriterConfig {
+            color: None,
+            text: "Loading".to_string(),
+            duration: 1400,
+            tick: None,
+        }
+    }
+
+    pub fn color(self, color: impl Into<String>) -> Self {
+        TypewriterConfig {
+            color: Some(color.into()),
+            ..self
+        }
+    }
+
+    pub fn text(self, text: impl Into<String>) -> Self {
+        TypewriterConfig {
+            text: text.into(),
+            ..self
+        }
+    }
+
+    pub fn duration(self, duration: u32) -> Self {
+        TypewriterConfig { duration, ..self }
+    }
+
+    /// Renders the text as it is typed out at a tick of the animation.
+    pub fn tick(self, tick: usize) -> Self {
+        TypewriterConfig {
+            tick: Some(tick),
+            ..self
+        }
+    }
+
+    /// The number of ticks in one cycle of the animation (one per character,
+    /// and a few more for holding the typed out text), and the time between
+    /// two of them.
+    pub fn ticks(&self) -> (usize, Duration) {
+        ticks(self.duration, self.text.chars().count())
+    }
+
+    // the text typed out by a tick
+    pub(crate) fn frame(&self, tick: usize) -> String {
+        self.text.chars().take(tick).collect()
+    }
+}
+
+impl Render for TypewriterConfig {
+    fn render(self) -> Result<RenderedLoader, ValidationError> {
+        let color = loader_color(self.color.as_deref())?;
+
+        let typed = self.frame(self.tick.unwrap_or(usize::MAX));
+        let content = element(
+            "span",
+            &[("class", "spux-visually-hidden")],
+            &escape(&self.text),
+        ) + &element("span", &[("aria-hidden", "true")], &escape(&typed))
+            + &element(
+                "span",
+                &[("class", "spux-typewriter-caret"), ("aria-hidden", "true")],
+                "",
+            );
+
+        Ok(RenderedLoader::new(
+            "span",
+            &[("class", "spux-typewriter"), ("role", "status")],
+            format!("color: {color}"),
+            content,
+            &format!("{VISUALLY_HIDDEN_STYLE}{STYLE}"),
+            &[
+                "spux-typewriter",
+                "spux-visually-hidden",
+                "spux-typewriter-caret",
+            ],
+        ))
+    }
+}
+
+/// Renders a piece of text that is typed out character by character
+/// behind a blinking caret, and then starts over again.
+///
+/// Takes in an optional color (#hex), which defaults to the color of the
+/// surrounding text, while `text` defaults to "Loading" and `duration` (by
+/// ms, for one full cycle) defaults to 1400. The font is inherited from the
+/// surrounding text.
+///
+/// # Example Usage
+/// ```
+/// use leptos::prelude::*;
+/// use spux::text::Typewriter;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <p><Typewriter color="#000000" text="Thinking" duration=2000 /></p>
+///     }
+/// }
+/// ```
+#[cfg(feature = "leptos")]
+#[component]
+pub fn Typewriter(
+    #[prop(default = None, into)] color: Option<&'static str>,
+    #[prop(default = "Loading")] text: &'static str,
+    #[prop(default = 1400)] duration: u32,
+) -> impl IntoView {
+    let mut config = TypewriterConfig::new().text(text).duration(duration);
+    if let Some(color) = color {
+        config = config.color(color);
+    }
+    let rendered = render_config(config.clone());
+
+    let tick = use_tick(duration, text.chars().count());
+    let config = StoredValue::new(config);
+    let typed = move || config.with_value(|config| config.frame(tick.get()));
+
+    view! {
+        {loader_style(rendered.css)}
+        <span class="spux-typewriter" role="status" style=rendered.style>
+            <span class="spux-visually-hidden">{text}</span>
+            <span aria-hidden="true">{typed}</span>
+            <span class="spux-typewriter-caret" aria-hidden="true"></span>
+        </span>
+    }
+}
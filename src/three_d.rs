@@ -0,0 +1,59 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+#[cfg(feature = "leptos")]
+use crate::core::Render;
+#[cfg(feature = "leptos")]
+use crate::private::{loader_style, render_config};
+use crate::size::Size;
+#[cfg(feature = "leptos")]
+use leptos::prelude::*;
+
+mod flip_square;
+#[cfg(feature = "leptos")]
+pub use flip_square::FlipSquare;
+pub use flip_square::FlipSquareConfig;
+
+mod cube;
+#[cfg(feature = "leptos")]
+pub use cube::Cube;
+pub use cube::CubeConfig;
+
+mod card_flip;
+#[cfg(feature = "leptos")]
+pub use card_flip::CardFlip;
+pub use card_flip::CardFlipConfig;
+
+// how far away (as a multiple of the loader's size) the viewer is placed from
+// the loader. smaller values exaggerate the depth of the 3d transforms
+const PERSPECTIVE_SCALE: u32 = 4;
+
+// the style of the outermost element, which holds the perspective that the
+// loader is viewed from
+fn root_style(size: Size) -> String {
+    format!(
+        "display: {}; vertical-align: {}; perspective: {}",
+        size.display(),
+        size.vertical_align(),
+        size.scaled(PERSPECTIVE_SCALE)
+    )
+}
+
+// renders any 3d loader from its config
+#[cfg(feature = "leptos")]
+fn render_three_d(config: impl Render, label: &'static str) -> impl IntoView {
+    let rendered = render_config(config);
+
+    view! {
+        {loader_style(rendered.css)}
+        <span
+            role="status"
+            aria-label=label
+            style=rendered.style
+            inner_html=rendered.content
+        ></span>
+    }
+}
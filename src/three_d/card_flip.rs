@@ -0,0 +1,178 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+#[cfg(feature = "leptos")]
+use super::render_three_d;
+use super::root_style;
+use crate::core::{element, loader_color, Render, RenderedLoader};
+use crate::errors::ValidationError;
+use crate::size::Size;
+#[cfg(feature = "leptos")]
+use leptos::prelude::*;
+
+const STYLE: &str = "
+    .spux-card-flip {
+        position: relative;
+        display:flex;
+        transform-style: preserve-3d;
+        animation: spux-card-flip 1400ms ease-in-out forwards;
+        animation-iteration-count: infinite;
+    }
+
+    .spux-card-flip-front,
+    .spux-card-flip-back {
+        position: absolute;
+        top: 0px;
+        left: 0px;
+        width: 100%;
+        height: 100%;
+        box-sizing: border-box;
+        backface-visibility: hidden;
+    }
+
+    .spux-card-flip-back {
+        transform: rotateY(180deg);
+    }
+
+    @keyframes spux-card-flip {
+        0% {
+            transform: rotateY(0deg);
+        }
+        50% {
+            transform: rotateY(180deg);
+        }
+        100% {
+            transform: rotateY(360deg);
+        }
+    }
+";
+
+/// A card that keeps flipping over, as rendered by the `CardFlip` component,
+/// see the [`core`](crate::core) module.
+///
+/// Takes in a size (by px, or by em to render it inline). The optional color
+/// (#hex) defaults to the color of the surrounding text and `label` defaults
+/// to "Loading".
+///
+/// # Example Usage
+/// ```
+/// use spux::core::render;
+/// use spux::three_d::CardFlipConfig;
+///
+/// let rendered = render(CardFlipConfig::new(16).label("Shuffling")).unwrap();
+/// assert!(rendered.html.contains("aria-label=\"Shuffling\""));
+/// assert!(rendered.html.contains("spux-card-flip-back"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CardFlipConfig {
+    color: Option<String>,
+    size: Size,
+    label: String,
+}
+
+impl CardFlipConfig {
+    pub fn new(size: impl Into<Size>) -> Self {
+        CardFlipConfig {
+            color: None,
+            size: size.into(),
+            label: "Loading".to_string(),
+        }
+    }
+
+    pub fn color(self, color: impl Into<String>) -> Self {
+        CardFlipConfig {
+            color: Some(color.into()),
+            ..self
+        }
+    }
+
+    pub fn label(self, label: impl Into<String>) -> Self {
+        CardFlipConfig {
+            label: label.into(),
+            ..self
+        }
+    }
+}
+
+impl Render for CardFlipConfig {
+    fn render(self) -> Result<RenderedLoader, ValidationError> {
+        let color = loader_color(self.color.as_deref())?;
+        self.size.validate()?;
+
+        let size = self.size;
+        let content = element(
+            "span",
+            &[
+                ("class", "spux-card-flip"),
+                ("style", &format!("width: {size}; height: {size}")),
+            ],
+            &(element(
+                "span",
+                &[
+                    ("class", "spux-card-flip-front"),
+                    ("style", &format!("background-color: {color}")),
+                ],
+                "",
+            ) + &element(
+                "span",
+                &[
+                    ("class", "spux-card-flip-back"),
+                    (
+                        "style",
+                        &format!("border: 1px solid; border-color: {color}"),
+                    ),
+                ],
+                "",
+            )),
+        );
+
+        Ok(RenderedLoader::new(
+            "span",
+            &[("role", "status"), ("aria-label", &self.label)],
+            root_style(size),
+            content,
+            STYLE,
+            &[
+                "spux-card-flip",
+                "spux-card-flip-front",
+                "spux-card-flip-back",
+            ],
+        ))
+    }
+}
+
+/// Renders a card that keeps flipping over, with a filled front and an
+/// outlined back.
+///
+/// Takes in required props for size (by px, or by em to render it inline) and
+/// an optional color (#hex), which defaults to the color of the surrounding text.
+/// `label` (announced to assistive technologies) defaults to "Loading".
+///
+/// # Example Usage
+/// ```
+/// use leptos::prelude::*;
+/// use spux::three_d::CardFlip;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <CardFlip color="#000000" size=16 />
+///     }
+/// }
+/// ```
+#[cfg(feature = "leptos")]
+#[component]
+pub fn CardFlip(
+    #[prop(default = None, into)] color: Option<&'static str>,
+    #[prop(into)] size: Size,
+    #[prop(default = "Loading")] label: &'static str,
+) -> impl IntoView {
+    let mut config = CardFlipConfig::new(size).label(label);
+    if let Some(color) = color {
+        config = config.color(color);
+    }
+    render_three_d(config, label)
+}
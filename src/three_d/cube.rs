@@ -0,0 +1,213 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+#[cfg(feature = "leptos")]
+use super::render_three_d;
+use super::root_style;
+use crate::core::{element, loader_color, Render, RenderedLoader};
+use crate::errors::ValidationError;
+use crate::size::Size;
+use crate::types::Validation;
+#[cfg(feature = "leptos")]
+use leptos::prelude::*;
+
+// the rotation of each face (front, back, right, left, top, bottom) before
+// it is pushed out from the center of the cube
+const FACES: [&str; 6] = [
+    "rotateY(0deg)",
+    "rotateY(180deg)",
+    "rotateY(90deg)",
+    "rotateY(-90deg)",
+    "rotateX(90deg)",
+    "rotateX(-90deg)",
+];
+
+const STYLE: &str = "
+    .spux-cube {
+        position: relative;
+        display:flex;
+        transform-style: preserve-3d;
+        animation: spux-cube 2800ms linear forwards;
+        animation-iteration-count: infinite;
+    }
+
+    .spux-cube-face {
+        position: absolute;
+        top: 0px;
+        left: 0px;
+        width: 100%;
+        height: 100%;
+        box-sizing: border-box;
+    }
+
+    @keyframes spux-cube {
+        0% {
+            transform: rotateX(-30deg) rotateY(0deg);
+        }
+        100% {
+            transform: rotateX(-30deg) rotateY(360deg);
+        }
+    }
+";
+
+/// A rotating cube, as rendered by the `Cube` component, see the
+/// [`core`](crate::core) module.
+///
+/// Takes in a size (by px, or by em to render it inline). The optional color
+/// (#hex) defaults to the color of the surrounding text and `label` defaults
+/// to "Loading". The cube is outlined unless it's given the `faces` (#hex) to
+/// fill each face (front, back, right, left, top and bottom) with.
+///
+/// # Example Usage
+/// ```
+/// use spux::core::render;
+/// use spux::size::Size;
+/// use spux::three_d::CubeConfig;
+///
+/// let rendered = render(CubeConfig::new(16)).unwrap();
+/// assert_eq!(rendered.html.matches("class=\"spux-cube-face\"").count(), 6);
+///
+/// let faces = ["#ff595e", "#ffca3a", "#8ac926", "#1982c4", "#6a4c93", "red"];
+/// assert!(render(CubeConfig::new(16).faces(faces)).is_err());
+/// assert!(render(CubeConfig::new(Size::Em(f64::NAN))).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CubeConfig {
+    color: Option<String>,
+    size: Size,
+    faces: Option<[String; 6]>,
+    label: String,
+}
+
+impl CubeConfig {
+    pub fn new(size: impl Into<Size>) -> Self {
+        CubeConfig {
+            color: None,
+            size: size.into(),
+            faces: None,
+            label: "Loading".to_string(),
+        }
+    }
+
+    pub fn color(self, color: impl Into<String>) -> Self {
+        CubeConfig {
+            color: Some(color.into()),
+            ..self
+        }
+    }
+
+    pub fn faces(self, faces: [impl Into<String>; 6]) -> Self {
+        CubeConfig {
+            faces: Some(faces.map(Into::into)),
+            ..self
+        }
+    }
+
+    pub fn label(self, label: impl Into<String>) -> Self {
+        CubeConfig {
+            label: label.into(),
+            ..self
+        }
+    }
+}
+
+impl Render for CubeConfig {
+    fn render(self) -> Result<RenderedLoader, ValidationError> {
+        let color = loader_color(self.color.as_deref())?;
+        self.size.validate()?;
+        if let Some(faces) = &self.faces {
+            faces
+                .iter()
+                .try_for_each(|face| face.as_str().validate_color_code().map(|_| ()))
+                .map_err(|_| {
+                    ValidationError::new(
+                        "Faces should be in the #hex format (e.g #000000) for black",
+                    )
+                })?;
+        }
+
+        let size = self.size;
+        let depth = size.divided(2);
+        let faces: String = FACES
+            .iter()
+            .enumerate()
+            .map(|(index, rotation)| {
+                let (border, background, backface) = match &self.faces {
+                    Some(faces) => ("none", faces[index].as_str(), "hidden"),
+                    None => ("1px solid", "transparent", "visible"),
+                };
+                let style = format!(
+                    "transform: {rotation} translateZ({depth}); border: {border}; \
+                     border-color: {color}; background-color: {background}; \
+                     backface-visibility: {backface}"
+                );
+                element(
+                    "span",
+                    &[("class", "spux-cube-face"), ("style", &style)],
+                    "",
+                )
+            })
+            .collect();
+        let content = element(
+            "span",
+            &[
+                ("class", "spux-cube"),
+                ("style", &format!("width: {size}; height: {size}")),
+            ],
+            &faces,
+        );
+
+        Ok(RenderedLoader::new(
+            "span",
+            &[("role", "status"), ("aria-label", &self.label)],
+            root_style(size),
+            content,
+            STYLE,
+            &["spux-cube", "spux-cube-face"],
+        ))
+    }
+}
+
+/// Renders a rotating cube.
+///
+/// Takes in required props for size (by px, or by em to render it inline) and
+/// an optional color (#hex), which defaults to the color of the surrounding text. By
+/// default the cube is drawn as an outline, where the back faces can be
+/// seen through the front faces. Passing `faces` fills each face (front,
+/// back, right, left, top and bottom) with its own #hex color instead.
+/// `label` (announced to assistive technologies) defaults to "Loading".
+///
+/// # Example Usage
+/// ```
+/// use leptos::prelude::*;
+/// use spux::three_d::Cube;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <Cube color="#000000" size=16
+///             faces=["#ff595e", "#ffca3a", "#8ac926", "#1982c4", "#6a4c93", "#000000"]
+///         />
+///     }
+/// }
+/// ```
+#[cfg(feature = "leptos")]
+#[component]
+pub fn Cube(
+    #[prop(default = None, into)] color: Option<&'static str>,
+    #[prop(into)] size: Size,
+    #[prop(default = None, into)] faces: Option<[&'static str; 6]>,
+    #[prop(default = "Loading")] label: &'static str,
+) -> impl IntoView {
+    let mut config = CubeConfig::new(size).label(label);
+    if let Some(color) = color {
+        config = config.color(color);
+    }
+    if let Some(faces) = faces {
+        config = config.faces(faces);
+    }
+    render_three_d(config, label)
+}
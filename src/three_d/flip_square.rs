@@ -0,0 +1,161 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+#[cfg(feature = "leptos")]
+use super::render_three_d;
+use super::root_style;
+use crate::core::{element, loader_color, Render, RenderedLoader};
+use crate::errors::ValidationError;
+use crate::size::Size;
+#[cfg(feature = "leptos")]
+use leptos::prelude::*;
+
+const STYLE: &str = "
+    .spux-flip-square {
+        display:flex;
+        box-sizing: border-box;
+        animation: spux-flip-square 1400ms ease-in-out forwards;
+        animation-iteration-count: infinite;
+    }
+
+    @keyframes spux-flip-square {
+        0% {
+            transform: rotateX(0deg) rotateY(0deg);
+        }
+        50% {
+            transform: rotateX(-180.1deg) rotateY(0deg);
+        }
+        100% {
+            transform: rotateX(-180deg) rotateY(-179.9deg);
+        }
+    }
+";
+
+/// A square that flips around its X axis and then around its Y axis, as
+/// rendered by the `FlipSquare` component, see the [`core`](crate::core)
+/// module.
+///
+/// Takes in a size (by px, or by em to render it inline). The optional color
+/// (#hex) defaults to the color of the surrounding text, the square is
+/// outlined unless it's `filled` and `label` defaults to "Loading".
+///
+/// # Example Usage
+/// ```
+/// use spux::core::render;
+/// use spux::three_d::FlipSquareConfig;
+///
+/// let rendered = render(FlipSquareConfig::new(16).color("#000000").filled(true)).unwrap();
+/// assert!(rendered.html.contains("perspective: 64px"));
+/// assert!(rendered.html.contains("background-color: #000000"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlipSquareConfig {
+    color: Option<String>,
+    size: Size,
+    filled: bool,
+    label: String,
+}
+
+impl FlipSquareConfig {
+    pub fn new(size: impl Into<Size>) -> Self {
+        FlipSquareConfig {
+            color: None,
+            size: size.into(),
+            filled: false,
+            label: "Loading".to_string(),
+        }
+    }
+
+    pub fn color(self, color: impl Into<String>) -> Self {
+        FlipSquareConfig {
+            color: Some(color.into()),
+            ..self
+        }
+    }
+
+    pub fn filled(self, filled: bool) -> Self {
+        FlipSquareConfig { filled, ..self }
+    }
+
+    pub fn label(self, label: impl Into<String>) -> Self {
+        FlipSquareConfig {
+            label: label.into(),
+            ..self
+        }
+    }
+}
+
+impl Render for FlipSquareConfig {
+    fn render(self) -> Result<RenderedLoader, ValidationError> {
+        let color = loader_color(self.color.as_deref())?;
+        self.size.validate()?;
+
+        let (border, background) = match self.filled {
+            true => ("none", color),
+            false => ("1px solid", "transparent"),
+        };
+        let size = self.size;
+        let content = element(
+            "span",
+            &[
+                ("class", "spux-flip-square"),
+                (
+                    "style",
+                    &format!(
+                        "width: {size}; height: {size}; border: {border}; border-color: {color}; \
+                         background-color: {background}"
+                    ),
+                ),
+            ],
+            "",
+        );
+
+        Ok(RenderedLoader::new(
+            "span",
+            &[("role", "status"), ("aria-label", &self.label)],
+            root_style(size),
+            content,
+            STYLE,
+            &["spux-flip-square"],
+        ))
+    }
+}
+
+/// Renders a square that flips around its X axis and then around its
+/// Y axis in turn.
+///
+/// Takes in required props for size (by px, or by em to render it inline) and
+/// an optional color (#hex), which defaults to the color of the surrounding text. The
+/// square is outlined like `Square` unless `filled` is set, in which case
+/// it is filled like `FilledSquare`.
+/// `label` (announced to assistive technologies) defaults to "Loading".
+///
+/// # Example Usage
+/// ```
+/// use leptos::prelude::*;
+/// use spux::three_d::FlipSquare;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <FlipSquare color="#000000" size=16 filled=true />
+///     }
+/// }
+/// ```
+#[cfg(feature = "leptos")]
+#[component]
+pub fn FlipSquare(
+    #[prop(default = None, into)] color: Option<&'static str>,
+    #[prop(into)] size: Size,
+    #[prop(optional)] filled: bool,
+    #[prop(default = "Loading")] label: &'static str,
+) -> impl IntoView {
+    let mut config = FlipSquareConfig::new(size).filled(filled).label(label);
+    if let Some(color) = color {
+        config = config.color(color);
+    }
+    render_three_d(config, label)
+}
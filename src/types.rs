@@ -52,3 +52,39 @@ impl Validation for &str {
         Ok(true)
     }
 }
+
+// derives lighter or darker variants of an already validated #hex color code so that
+// components can offer sensible defaults (e.g highlights and tracks) from a single color
+#[cfg(any(feature = "skeletons", feature = "text"))]
+pub trait Shading {
+    fn mix(&self, other: (u8, u8, u8), amount: f64) -> String;
+
+    fn lighten(&self, amount: f64) -> String {
+        self.mix((255, 255, 255), amount)
+    }
+}
+
+#[cfg(any(feature = "skeletons", feature = "text"))]
+impl Shading for &str {
+    fn mix(&self, other: (u8, u8, u8), amount: f64) -> String {
+        let amount = amount.clamp(0.0, 1.0);
+        let channel = |index: usize| u8::from_str_radix(&self[index..index + 2], 16).unwrap_or(0);
+        let blend =
+            |from: u8, to: u8| (from as f64 + (to as f64 - from as f64) * amount).round() as u8;
+
+        format!(
+            "#{:02x}{:02x}{:02x}",
+            blend(channel(1), other.0),
+            blend(channel(3), other.1),
+            blend(channel(5), other.2)
+        )
+    }
+}
+
+// the red, green and blue of a #hex color code
+#[cfg(any(feature = "raster", feature = "terminal"))]
+pub fn rgb(color: &str) -> Result<(u8, u8, u8), ValidationError> {
+    color.validate_color_code()?;
+    let channel = |index: usize| u8::from_str_radix(&color[index..index + 2], 16).unwrap_or(0);
+    Ok((channel(1), channel(3), channel(5)))
+}
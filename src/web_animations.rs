@@ -0,0 +1,286 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+/*!
+ Driving loaders with the Web Animations API.
+
+ With `renderer=Renderer::WebAnimations(animation)`, a loader renders its SVG
+ drawing with presentation attributes and is animated through
+ `Element::animate`, so no `<style>` is injected into the page (e.g for apps
+ under a strict Content Security Policy).
+
+ The [`LoaderAnimation`] handle controls the animation from Rust: it can be
+ paused, seeked and sped up, and its [`finished`](LoaderAnimation::finished)
+ future resolves once a loader with a limited number of `iterations` is done.
+
+```
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use spux::spinners::PartialCircle;
+use spux::svg::Renderer;
+use spux::web_animations::LoaderAnimation;
+
+#[component]
+fn App() -> impl IntoView {
+    let animation = LoaderAnimation::new().iterations(3);
+    // the task starts before the loader is mounted, and `finished` waits
+    // for it to be animated
+    spawn_local(async move {
+        if animation.finished().await.is_ok() {
+            // the loader went around 3 times
+        }
+    });
+
+    view! {
+        <PartialCircle color="#000000" size=16 renderer=Renderer::WebAnimations(animation) />
+        <button on:click=move |_| animation.set_playback_rate(2.0)>"Faster"</button>
+    }
+}
+```
+*/
+use crate::keyframes::{Easing, Keyframes};
+use crate::svg::SvgLoader;
+use js_sys::{Array, Function, Object, Reflect};
+use leptos::prelude::*;
+use leptos::svg::Svg;
+use std::future;
+use std::mem;
+use std::task::{Poll, Waker};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::Animation;
+
+/// A handle to the Web Animations API animation of a loader.
+///
+/// The handle is attached to the animation once the loader is mounted, and
+/// every method (but [`finished`](Self::finished), which waits for it) does
+/// nothing before that.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoaderAnimation {
+    attachment: StoredValue<Attachment, LocalStorage>,
+    iterations: Option<u32>,
+}
+
+// the animation of a handle, along with the tasks waiting for it to start
+#[derive(Debug)]
+enum Attachment {
+    Pending(Vec<Waker>),
+    Attached(Animation),
+    Detached,
+}
+
+impl Default for LoaderAnimation {
+    fn default() -> Self {
+        LoaderAnimation::new()
+    }
+}
+
+impl LoaderAnimation {
+    /// A handle to an animation that loops forever.
+    pub fn new() -> Self {
+        LoaderAnimation {
+            attachment: StoredValue::new_local(Attachment::Pending(Vec::new())),
+            iterations: None,
+        }
+    }
+
+    /// Stops the animation after a number of cycles.
+    pub fn iterations(self, iterations: u32) -> Self {
+        LoaderAnimation {
+            iterations: Some(iterations),
+            ..self
+        }
+    }
+
+    /// The underlying animation (e.g to change its composite operation).
+    pub fn animation(&self) -> Option<Animation> {
+        self.attachment
+            .try_with_value(|attachment| match attachment {
+                Attachment::Attached(animation) => Some(animation.clone()),
+                _ => None,
+            })
+            .flatten()
+    }
+
+    pub fn play(&self) {
+        if let Some(animation) = self.animation() {
+            _ = animation.play();
+        }
+    }
+
+    pub fn pause(&self) {
+        if let Some(animation) = self.animation() {
+            _ = animation.pause();
+        }
+    }
+
+    pub fn cancel(&self) {
+        if let Some(animation) = self.animation() {
+            animation.cancel();
+        }
+    }
+
+    /// Moves the animation to a `time` (by ms).
+    pub fn seek(&self, time: f64) {
+        if let Some(animation) = self.animation() {
+            animation.set_current_time(Some(time));
+        }
+    }
+
+    /// The current time (by ms) of the animation.
+    pub fn current_time(&self) -> Option<f64> {
+        self.animation()
+            .and_then(|animation| animation.current_time())
+    }
+
+    /// Speeds up (above 1) or slows down (below 1) the animation.
+    pub fn set_playback_rate(&self, rate: f64) {
+        if let Some(animation) = self.animation() {
+            animation.set_playback_rate(rate);
+        }
+    }
+
+    /// Resolves once the animation has finished its `iterations`, waiting
+    /// for the loader to be mounted first, and fails if it is canceled (or
+    /// the loader is unmounted before it was animated).
+    pub async fn finished(self) -> Result<(), JsValue> {
+        let animation = future::poll_fn(|context| {
+            self.attachment
+                .try_update_value(|attachment| match attachment {
+                    Attachment::Pending(wakers) => {
+                        wakers.push(context.waker().clone());
+                        Poll::Pending
+                    }
+                    Attachment::Attached(animation) => Poll::Ready(Some(animation.clone())),
+                    Attachment::Detached => Poll::Ready(None),
+                })
+                // the handle was disposed of along with the component
+                .unwrap_or(Poll::Ready(None))
+        })
+        .await;
+        let Some(animation) = animation else {
+            return Err(JsValue::from_str(
+                "Spux Error - The loader was unmounted before it was animated",
+            ));
+        };
+        JsFuture::from(animation.finished()?).await.map(|_| ())
+    }
+
+    fn attach(&self, animation: Animation) {
+        self.settle(Attachment::Attached(animation));
+    }
+
+    // the loader was unmounted (or couldn't be animated) before the
+    // animation was attached
+    fn detach(&self) {
+        let pending = self
+            .attachment
+            .try_with_value(|attachment| matches!(attachment, Attachment::Pending(_)));
+        if pending == Some(true) {
+            self.settle(Attachment::Detached);
+        }
+    }
+
+    fn settle(&self, settled: Attachment) {
+        let previous = self
+            .attachment
+            .try_update_value(|attachment| mem::replace(attachment, settled));
+        if let Some(Attachment::Pending(wakers)) = previous {
+            wakers.into_iter().for_each(Waker::wake);
+        }
+    }
+}
+
+// renders the SVG drawing of a loader without a stylesheet, and animates it
+// once it is mounted
+pub(crate) fn animated_svg(
+    svg: SvgLoader,
+    animation: LoaderAnimation,
+    color: &'static str,
+    duration: u32,
+) -> impl IntoView {
+    let root = NodeRef::<Svg>::new();
+    let body = svg.inline_body(color);
+    let easing = svg.timing();
+    let keyframes = StoredValue::new(svg.keyframes().clone());
+
+    Effect::new(move |_| {
+        let Some(shape) = root.get().and_then(|root| root.first_element_child()) else {
+            return;
+        };
+
+        let options = Object::new();
+        let iterations = animation
+            .iterations
+            .map_or(f64::INFINITY, |iterations| iterations as f64);
+        _ = Reflect::set(&options, &"duration".into(), &(duration as f64).into());
+        _ = Reflect::set(&options, &"iterations".into(), &iterations.into());
+        let frames = keyframes.with_value(|keyframes| animation_frames(keyframes, easing));
+
+        // `Element::animate` is still an unstable API in web-sys
+        let animate = Reflect::get(&shape, &"animate".into())
+            .ok()
+            .and_then(|animate| animate.dyn_into::<Function>().ok());
+        match animate.map(|animate| animate.call2(&shape, &frames, &options)) {
+            Some(Ok(started)) => animation.attach(started.unchecked_into()),
+            _ => animation.detach(),
+        }
+    });
+    on_cleanup(move || {
+        animation.cancel();
+        animation.detach();
+    });
+
+    view! {
+        <svg node_ref=root
+            viewBox="0 0 100 100"
+            width="100%"
+            height="100%"
+            aria-hidden="true"
+            inner_html=body
+        ></svg>
+    }
+}
+
+// the keyframes as Web Animations API keyframes, easing each step like
+// the `animation-timing-function` of CSS does
+fn animation_frames(keyframes: &Keyframes, easing: Easing) -> Array {
+    keyframes
+        .steps()
+        .iter()
+        .map(|(offset, step)| {
+            let frame = Object::new();
+            _ = Reflect::set(&frame, &"offset".into(), &(offset.0 / 100.0).into());
+            _ = Reflect::set(&frame, &"easing".into(), &easing.css().into());
+            if !step.transforms().is_empty() {
+                let transform = step
+                    .transforms()
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                _ = Reflect::set(&frame, &"transform".into(), &transform.into());
+            }
+            for (property, value) in step.properties() {
+                let name = camel_case(property.name());
+                _ = Reflect::set(&frame, &name.into(), &value.to_string().into());
+            }
+            JsValue::from(frame)
+        })
+        .collect()
+}
+
+// e.g margin-top to marginTop, as properties are named in JavaScript
+fn camel_case(name: &str) -> String {
+    name.split('-')
+        .enumerate()
+        .map(|(index, word)| match index {
+            0 => word.to_string(),
+            _ => word[..1].to_uppercase() + &word[1..],
+        })
+        .collect()
+}
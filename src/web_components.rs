@@ -0,0 +1,158 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+/*!
+ The Spux loaders as Web Components.
+
+ With the `web-components` feature, every built-in loader can be registered
+ as a custom element named after it (e.g `<spux-circle>`), for apps that
+ aren't built with Rust. The elements take the same props as the components,
+ as attributes that are observed (so changing them re-renders the loader):
+
+```html
+<spux-circle color="#000000" size="12"></spux-circle>
+<spux-partial-circle size="1.5em" duration="900" label="Saving" renderer="svg"></spux-partial-circle>
+```
+
+ The `size` defaults to 24px and the `renderer` to the default renderer (see
+ the [`svg`](crate::svg) module). Each element renders its loader into its
+ own shadow DOM, so the styles of the loader and of the page don't leak into
+ each other.
+
+ A drop-in bundle is built from the `web-components` example (see its
+ README) and loaded with:
+
+```js
+import init, { defineSpuxElements } from "./pkg/web_components.js";
+
+await init();
+defineSpuxElements();
+```
+
+ Custom loaders declared with `spux_loader!` are registered with
+ [`define_element`].
+*/
+use crate::core::{self, LoaderConfig};
+use crate::errors::ValidationError;
+use crate::loader::{self, LoaderDefinition};
+use crate::size::Size;
+use crate::svg::Renderer;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(inline_js = r#"
+export function define_spux_element(name, render) {
+    if (customElements.get(name)) {
+        return;
+    }
+    customElements.define(name, class extends HTMLElement {
+        static get observedAttributes() {
+            return ["color", "size", "duration", "label", "renderer"];
+        }
+
+        constructor() {
+            super();
+            this.attachShadow({ mode: "open" });
+        }
+
+        connectedCallback() {
+            this.render();
+        }
+
+        attributeChangedCallback() {
+            if (this.isConnected) {
+                this.render();
+            }
+        }
+
+        render() {
+            try {
+                this.shadowRoot.innerHTML = render(
+                    this.getAttribute("color"),
+                    this.getAttribute("size"),
+                    this.getAttribute("duration"),
+                    this.getAttribute("label"),
+                    this.getAttribute("renderer"),
+                );
+            } catch (error) {
+                console.error(error);
+            }
+        }
+    });
+}
+"#)]
+extern "C" {
+    fn define_spux_element(name: &str, render: &JsValue);
+}
+
+/// Registers every built-in loader (of the enabled `spinners` and `pulsers`
+/// features) as a custom element, e.g `<spux-circle>`.
+#[wasm_bindgen(js_name = defineSpuxElements)]
+pub fn define_elements() {
+    for (name, definition) in loader::built_in() {
+        define_element(&format!("spux-{name}"), definition);
+    }
+}
+
+/// Registers a loader as a custom element of the given `name` (which has to
+/// contain a `-`). Registering a name that is already taken does nothing.
+pub fn define_element(name: &str, definition: LoaderDefinition) {
+    let render = Closure::<
+        dyn Fn(
+            Option<String>,
+            Option<String>,
+            Option<String>,
+            Option<String>,
+            Option<String>,
+        ) -> Result<String, JsError>,
+    >::new(move |color, size, duration, label, renderer| {
+        render_element(definition.clone(), color, size, duration, label, renderer)
+            .map_err(|error| JsError::new(&format!("Spux Error - {error}")))
+    });
+    // the element is never unregistered, so neither is its renderer
+    define_spux_element(name, &render.into_js_value());
+}
+
+// the content of the shadow root of an element, from its attributes
+fn render_element(
+    definition: LoaderDefinition,
+    color: Option<String>,
+    size: Option<String>,
+    duration: Option<String>,
+    label: Option<String>,
+    renderer: Option<String>,
+) -> Result<String, ValidationError> {
+    let size = match size {
+        Some(size) => size.parse()?,
+        None => Size::Px(24),
+    };
+    let mut config = LoaderConfig::new(definition, size);
+    if let Some(color) = color {
+        config = config.color(color);
+    }
+    if let Some(duration) = duration {
+        let duration = duration
+            .trim()
+            .parse()
+            .map_err(|_| ValidationError::new("Duration should be a number of ms (e.g 1400)"))?;
+        config = config.duration(duration);
+    }
+    if let Some(label) = label {
+        config = config.label(label);
+    }
+    match renderer.as_deref() {
+        Some("css") => config = config.renderer(Renderer::Css),
+        Some("svg") => config = config.renderer(Renderer::Svg),
+        _ => {}
+    }
+
+    let rendered = core::render(config)?;
+    Ok(format!(
+        "<style>:host{{display:inline-flex}}{}</style>{}",
+        rendered.css, rendered.html
+    ))
+}
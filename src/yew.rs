@@ -0,0 +1,92 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+/*!
+ The Spux loaders as Yew function components.
+
+ With the `yew` feature, every family of loaders (the `spinners`, `pulsers`,
+ `progress` bars, `skeletons`, `text`, `grids`, `morphers` and `three_d`
+ loaders) comes as Yew function components with the same props as their
+ Leptos counterparts, rendered from the same definitions and configs (see
+ the [`core`](crate::core) module).
+
+```rust,ignore
+use spux::yew::pulsers::Circle;
+use yew::prelude::*;
+
+#[function_component]
+fn App() -> Html {
+    html! {
+        <Circle color="#000000" size={10} />
+    }
+}
+```
+
+ Custom loaders declared with `spux_loader!` are rendered by [`SpuxLoader`]
+ from their definition.
+*/
+
+// declares a Yew function component for a built-in loader, from its definition
+#[cfg(any(feature = "spinners", feature = "pulsers"))]
+macro_rules! yew_loader {
+    ($(#[$meta:meta])* $name:ident => $definition:path) => {
+        $(#[$meta])*
+        #[::yew::function_component]
+        pub fn $name(props: &$crate::yew::LoaderProps) -> ::yew::Html {
+            $crate::yew::render($definition(), props)
+        }
+    };
+}
+
+mod spux_loader;
+#[cfg(any(feature = "spinners", feature = "pulsers"))]
+use spux_loader::render;
+pub use spux_loader::{LoaderProps, SpuxLoader, SpuxLoaderProps};
+
+#[cfg(feature = "spinners")]
+pub mod spinners;
+
+#[cfg(feature = "pulsers")]
+pub mod pulsers;
+
+#[cfg(feature = "progress")]
+pub mod progress;
+
+#[cfg(feature = "skeletons")]
+pub mod skeletons;
+
+#[cfg(feature = "text")]
+pub mod text;
+
+#[cfg(feature = "grids")]
+pub mod grids;
+
+#[cfg(feature = "morphers")]
+pub mod morphers;
+
+#[cfg(feature = "three_d")]
+pub mod three_d;
+
+// renders a component from the markup and CSS that `core::render` renders
+// its config into
+#[cfg(any(
+    feature = "skeletons",
+    feature = "text",
+    feature = "grids",
+    feature = "morphers",
+    feature = "three_d"
+))]
+fn render_component(config: impl crate::core::Render) -> ::yew::Html {
+    let rendered = crate::private::render_config(config);
+
+    ::yew::html! {
+        <>
+            <style>{ ::yew::Html::from_html_unchecked(rendered.css.into()) }</style>
+            { ::yew::Html::from_html_unchecked(rendered.html.into()) }
+        </>
+    }
+}
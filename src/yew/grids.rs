@@ -0,0 +1,90 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! The [`grids`](crate::grids) as Yew function components.
+
+use super::render_component;
+use crate::grids::{cube_grid_config, folding_cube_config, GridConfig, GridEffect, Stagger};
+use crate::size::Size;
+use yew::prelude::*;
+
+/// The props of [`Grid`], which are the same as with Leptos.
+#[derive(Properties, Clone, PartialEq)]
+pub struct GridProps {
+    #[prop_or_default]
+    pub color: Option<AttrValue>,
+    pub size: Size,
+    #[prop_or(3)]
+    pub rows: u32,
+    #[prop_or(3)]
+    pub cols: u32,
+    #[prop_or(2)]
+    pub gap: u32,
+    #[prop_or_default]
+    pub pattern: Stagger,
+    #[prop_or_default]
+    pub effect: GridEffect,
+    #[prop_or(1400)]
+    pub duration: u32,
+    #[prop_or(AttrValue::Static("Loading"))]
+    pub label: AttrValue,
+}
+
+/// The props of [`CubeGrid`] and [`FoldingCube`], which are the same as with
+/// Leptos.
+#[derive(Properties, Clone, PartialEq)]
+pub struct GridPresetProps {
+    #[prop_or_default]
+    pub color: Option<AttrValue>,
+    pub size: Size,
+    #[prop_or(AttrValue::Static("Loading"))]
+    pub label: AttrValue,
+}
+
+/// Renders a grid of filled cells that animate one after the other, see
+/// `spux::grids::Grid`.
+#[function_component]
+pub fn Grid(props: &GridProps) -> Html {
+    let config = GridConfig::new(props.size)
+        .rows(props.rows)
+        .cols(props.cols)
+        .gap(props.gap)
+        .pattern(props.pattern)
+        .effect(props.effect)
+        .duration(props.duration);
+    render_grid(config, props.color.as_deref(), &props.label)
+}
+
+/// Renders a 3x3 grid of cubes that shrink away and grow back in a diagonal
+/// wave, see `spux::grids::CubeGrid`.
+#[function_component]
+pub fn CubeGrid(props: &GridPresetProps) -> Html {
+    render_grid(
+        cube_grid_config(props.size),
+        props.color.as_deref(),
+        &props.label,
+    )
+}
+
+/// Renders a cube split into 4 cells that fold in and out of view one after
+/// the other, see `spux::grids::FoldingCube`.
+#[function_component]
+pub fn FoldingCube(props: &GridPresetProps) -> Html {
+    render_grid(
+        folding_cube_config(props.size),
+        props.color.as_deref(),
+        &props.label,
+    )
+}
+
+fn render_grid(config: GridConfig, color: Option<&str>, label: &str) -> Html {
+    let config = config.label(label);
+    render_component(match color {
+        Some(color) => config.color(color),
+        None => config,
+    })
+}
@@ -0,0 +1,42 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! The [`morphers`](crate::morphers) as Yew function components.
+
+use super::render_component;
+use crate::morphers::MorphConfig;
+use crate::shapes::Shape;
+use crate::size::Size;
+use yew::prelude::*;
+
+/// The props of [`Morph`], which are the same as with Leptos.
+#[derive(Properties, Clone, PartialEq)]
+pub struct MorphProps {
+    #[prop_or_default]
+    pub color: Option<AttrValue>,
+    pub size: Size,
+    #[prop_or_else(|| vec![Shape::Square, Shape::Circle, Shape::Diamond])]
+    pub shapes: Vec<Shape>,
+    #[prop_or(1400)]
+    pub duration: u32,
+    #[prop_or(AttrValue::Static("Loading"))]
+    pub label: AttrValue,
+}
+
+/// Renders a filled shape that morphs into the next shape of the sequence,
+/// see `spux::morphers::Morph`.
+#[function_component]
+pub fn Morph(props: &MorphProps) -> Html {
+    let mut config = MorphConfig::new(props.size)
+        .shapes(props.shapes.clone())
+        .duration(props.duration)
+        .label(props.label.as_str());
+    if let Some(color) = &props.color {
+        config = config.color(color.as_str());
+    }
+    render_component(config)
+}
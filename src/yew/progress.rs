@@ -0,0 +1,116 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! The [`progress`](crate::progress) bars as Yew function components.
+
+use crate::private::render_config;
+use crate::progress::{CircularProgressConfig, LinearProgressConfig};
+use yew::prelude::*;
+
+/// The props of [`LinearProgress`] and [`CircularProgress`], which are the
+/// same as with Leptos: a required `size` (by px), an optional `color` (#hex)
+/// that defaults to the color of the surrounding text, an optional `value`
+/// and `buffer` (0.0 to 1.0) and an optional `label` (defaults to "Loading").
+#[derive(Properties, Clone, PartialEq)]
+pub struct ProgressProps {
+    #[prop_or_default]
+    pub color: Option<AttrValue>,
+    pub size: u32,
+    #[prop_or_default]
+    pub value: Option<f64>,
+    #[prop_or_default]
+    pub buffer: Option<f64>,
+    #[prop_or(AttrValue::Static("Loading"))]
+    pub label: AttrValue,
+}
+
+/// Renders a horizontal progress bar that fills the width of its
+/// container, see `spux::progress::LinearProgress`.
+#[function_component]
+pub fn LinearProgress(props: &ProgressProps) -> Html {
+    let mut config = LinearProgressConfig::new(props.size)
+        .label(props.label.as_str())
+        .value(props.value)
+        .buffer(props.buffer);
+    if let Some(color) = &props.color {
+        config = config.color(color.as_str());
+    }
+    let rendered = render_config(config.clone());
+
+    // the bars are kept (rather than rendered again) when the value changes,
+    // so that their width transitions smoothly
+    html! {
+        <>
+            <style>{ Html::from_html_unchecked(rendered.css.into()) }</style>
+            <div class="spux-linear-progress"
+                role="progressbar"
+                aria-label={props.label.clone()}
+                aria-valuemin="0"
+                aria-valuemax="100"
+                aria-valuenow={config.value_now()}
+                style={rendered.style}
+            >
+                <div class="spux-linear-progress-buffer" style={config.buffer_style()}></div>
+                <div class={config.bar_class()} style={config.bar_style()}></div>
+            </div>
+        </>
+    }
+}
+
+/// Renders a circular progress ring, see `spux::progress::CircularProgress`.
+#[function_component]
+pub fn CircularProgress(props: &ProgressProps) -> Html {
+    let mut config = CircularProgressConfig::new(props.size)
+        .label(props.label.as_str())
+        .value(props.value)
+        .buffer(props.buffer);
+    if let Some(color) = &props.color {
+        config = config.color(color.as_str());
+    }
+    let rendered = render_config(config.clone());
+
+    let size = props.size.to_string();
+    let (center, radius) = (config.center().to_string(), config.radius().to_string());
+    let stroke_width = config.stroke_width().to_string();
+    let circumference = config.circumference().to_string();
+
+    // the rings are kept (rather than rendered again) when the value
+    // changes, so that their offset transitions smoothly
+    let rings = config
+        .rings()
+        .into_iter()
+        .map(|(stroke, offset)| {
+            let dasharray = offset.as_ref().map(|_| circumference.clone());
+            html! {
+                <circle cx={center.clone()} cy={center.clone()} r={radius.clone()}
+                    stroke={stroke}
+                    stroke-width={stroke_width.clone()}
+                    stroke-dasharray={dasharray}
+                    stroke-dashoffset={offset}
+                ></circle>
+            }
+        })
+        .collect::<Html>();
+
+    html! {
+        <>
+            <style>{ Html::from_html_unchecked(rendered.css.into()) }</style>
+            <svg class={config.class()}
+                role="progressbar"
+                aria-label={props.label.clone()}
+                aria-valuemin="0"
+                aria-valuemax="100"
+                aria-valuenow={config.value_now()}
+                width={size.clone()}
+                height={size.clone()}
+                viewBox={format!("0 0 {size} {size}")}
+            >
+                { rings }
+            </svg>
+        </>
+    }
+}
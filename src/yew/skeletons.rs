@@ -0,0 +1,131 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! The [`skeletons`](crate::skeletons) as Yew function components.
+
+use super::render_component;
+use crate::skeletons::{
+    SkeletonAnimation, SkeletonAvatarConfig, SkeletonCardConfig, SkeletonRectConfig,
+    SkeletonTableConfig, SkeletonTextConfig,
+};
+use yew::prelude::*;
+
+/// The props of [`SkeletonRect`], which are the same as with Leptos.
+#[derive(Properties, Clone, PartialEq)]
+pub struct SkeletonRectProps {
+    pub color: AttrValue,
+    pub height: u32,
+    #[prop_or_default]
+    pub width: Option<u32>,
+    #[prop_or_default]
+    pub highlight: Option<AttrValue>,
+    #[prop_or_default]
+    pub animation: SkeletonAnimation,
+}
+
+/// Renders a rectangular placeholder, see `spux::skeletons::SkeletonRect`.
+#[function_component]
+pub fn SkeletonRect(props: &SkeletonRectProps) -> Html {
+    let mut config = SkeletonRectConfig::new(props.color.as_str(), props.height)
+        .width(props.width)
+        .animation(props.animation);
+    if let Some(highlight) = &props.highlight {
+        config = config.highlight(highlight.as_str());
+    }
+    render_component(config)
+}
+
+/// The props of [`SkeletonAvatar`] and [`SkeletonCard`], which are the same
+/// as with Leptos.
+#[derive(Properties, Clone, PartialEq)]
+pub struct SkeletonProps {
+    pub color: AttrValue,
+    pub size: u32,
+    #[prop_or_default]
+    pub highlight: Option<AttrValue>,
+    #[prop_or_default]
+    pub animation: SkeletonAnimation,
+}
+
+/// Renders a circular placeholder, see `spux::skeletons::SkeletonAvatar`.
+#[function_component]
+pub fn SkeletonAvatar(props: &SkeletonProps) -> Html {
+    let mut config =
+        SkeletonAvatarConfig::new(props.color.as_str(), props.size).animation(props.animation);
+    if let Some(highlight) = &props.highlight {
+        config = config.highlight(highlight.as_str());
+    }
+    render_component(config)
+}
+
+/// Renders a card shaped placeholder, see `spux::skeletons::SkeletonCard`.
+#[function_component]
+pub fn SkeletonCard(props: &SkeletonProps) -> Html {
+    let mut config =
+        SkeletonCardConfig::new(props.color.as_str(), props.size).animation(props.animation);
+    if let Some(highlight) = &props.highlight {
+        config = config.highlight(highlight.as_str());
+    }
+    render_component(config)
+}
+
+/// The props of [`SkeletonText`], which are the same as with Leptos.
+#[derive(Properties, Clone, PartialEq)]
+pub struct SkeletonTextProps {
+    pub color: AttrValue,
+    pub size: u32,
+    #[prop_or(3)]
+    pub lines: u32,
+    #[prop_or(60)]
+    pub last_line_width: u32,
+    #[prop_or_default]
+    pub highlight: Option<AttrValue>,
+    #[prop_or_default]
+    pub animation: SkeletonAnimation,
+}
+
+/// Renders a paragraph shaped placeholder, see
+/// `spux::skeletons::SkeletonText`.
+#[function_component]
+pub fn SkeletonText(props: &SkeletonTextProps) -> Html {
+    let mut config = SkeletonTextConfig::new(props.color.as_str(), props.size)
+        .lines(props.lines)
+        .last_line_width(props.last_line_width)
+        .animation(props.animation);
+    if let Some(highlight) = &props.highlight {
+        config = config.highlight(highlight.as_str());
+    }
+    render_component(config)
+}
+
+/// The props of [`SkeletonTable`], which are the same as with Leptos.
+#[derive(Properties, Clone, PartialEq)]
+pub struct SkeletonTableProps {
+    pub color: AttrValue,
+    pub size: u32,
+    #[prop_or(5)]
+    pub rows: u32,
+    #[prop_or(4)]
+    pub cols: u32,
+    #[prop_or_default]
+    pub highlight: Option<AttrValue>,
+    #[prop_or_default]
+    pub animation: SkeletonAnimation,
+}
+
+/// Renders a table shaped placeholder, see `spux::skeletons::SkeletonTable`.
+#[function_component]
+pub fn SkeletonTable(props: &SkeletonTableProps) -> Html {
+    let mut config = SkeletonTableConfig::new(props.color.as_str(), props.size)
+        .rows(props.rows)
+        .cols(props.cols)
+        .animation(props.animation);
+    if let Some(highlight) = &props.highlight {
+        config = config.highlight(highlight.as_str());
+    }
+    render_component(config)
+}
@@ -0,0 +1,32 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! The [`spinners`](crate::spinners) as Yew function components.
+
+use crate::spinners::{
+    filled_square_definition, partial_circle_definition, square_definition, triangle_definition,
+};
+
+yew_loader! {
+    /// Renders a square and rotates it immediately on the screen.
+    Square => square_definition
+}
+
+yew_loader! {
+    /// Renders a filled square and rotates it immediately on the screen.
+    FilledSquare => filled_square_definition
+}
+
+yew_loader! {
+    /// Renders a partial circle and rotates it immediately on the screen.
+    PartialCircle => partial_circle_definition
+}
+
+yew_loader! {
+    /// Renders a triangle and rotates it immediately on the screen.
+    Triangle => triangle_definition
+}
@@ -0,0 +1,101 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+use crate::loader::LoaderDefinition;
+use crate::private::{loader_config, render_config};
+use crate::size::Size;
+use crate::svg::Renderer;
+use yew::html::IntoPropValue;
+use yew::prelude::*;
+
+/// The props of every Spux loader, which are the same as with Leptos: a
+/// required `size` (by px, or by em to render it inline), an optional
+/// `color` (#hex) that defaults to the color of the surrounding text, an
+/// optional `duration` (by ms), an optional `label` (defaults to "Loading")
+/// and an optional `renderer`.
+#[derive(Properties, Clone, PartialEq)]
+pub struct LoaderProps {
+    #[prop_or_default]
+    pub color: Option<AttrValue>,
+    pub size: Size,
+    #[prop_or_default]
+    pub duration: Option<u32>,
+    #[prop_or(AttrValue::Static("Loading"))]
+    pub label: AttrValue,
+    #[prop_or_default]
+    pub renderer: Option<Renderer>,
+}
+
+/// The props of [`SpuxLoader`], see [`LoaderProps`].
+#[derive(Properties, Clone, PartialEq)]
+pub struct SpuxLoaderProps {
+    pub definition: LoaderDefinition,
+    #[prop_or_default]
+    pub color: Option<AttrValue>,
+    pub size: Size,
+    #[prop_or_default]
+    pub duration: Option<u32>,
+    #[prop_or(AttrValue::Static("Loading"))]
+    pub label: AttrValue,
+    #[prop_or_default]
+    pub renderer: Option<Renderer>,
+}
+
+// lets the size be given by px, e.g `size={24}`
+impl IntoPropValue<Size> for u32 {
+    fn into_prop_value(self) -> Size {
+        Size::Px(self)
+    }
+}
+
+/// Renders any loader from its definition, e.g a custom loader declared
+/// with `spux_loader!` (and named after a function).
+#[function_component]
+pub fn SpuxLoader(props: &SpuxLoaderProps) -> Html {
+    let SpuxLoaderProps {
+        definition,
+        color,
+        size,
+        duration,
+        label,
+        renderer,
+    } = props.clone();
+    render(
+        definition,
+        &LoaderProps {
+            color,
+            size,
+            duration,
+            label,
+            renderer,
+        },
+    )
+}
+
+// renders a loader from the markup and CSS that `core::render` renders it into
+pub(crate) fn render(definition: LoaderDefinition, props: &LoaderProps) -> Html {
+    let rendered = render_config(loader_config(
+        definition,
+        props.color.as_deref(),
+        props.size,
+        props.duration,
+        &props.label,
+        props.renderer,
+    ));
+
+    html! {
+        <>
+            <style>{ Html::from_html_unchecked(rendered.css.into()) }</style>
+            <span class="spux-loader"
+                role="status"
+                aria-label={props.label.clone()}
+                style={rendered.style}
+            >
+                { Html::from_html_unchecked(rendered.content.into()) }
+            </span>
+        </>
+    }
+}
@@ -0,0 +1,144 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! The [`text`](crate::text) loaders as Yew function components.
+
+use super::render_component;
+use crate::private::render_config;
+use crate::text::{EllipsisConfig, ScrambleConfig, ShimmerTextConfig, TypewriterConfig};
+use gloo_timers::callback::Interval;
+use std::time::Duration;
+use yew::prelude::*;
+
+/// The props of the text loaders, which are the same as with Leptos: an
+/// optional `color` (#hex) that defaults to the color of the surrounding
+/// text, an optional `text` (defaults to "Loading") and an optional
+/// `duration` (by ms, defaults to 1400).
+#[derive(Properties, Clone, PartialEq)]
+pub struct TextProps {
+    #[prop_or_default]
+    pub color: Option<AttrValue>,
+    #[prop_or(AttrValue::Static("Loading"))]
+    pub text: AttrValue,
+    #[prop_or(1400)]
+    pub duration: u32,
+}
+
+/// The props of [`ShimmerText`], see [`TextProps`]. The `highlight` color
+/// defaults to a lighter shade of `color`.
+#[derive(Properties, Clone, PartialEq)]
+pub struct ShimmerTextProps {
+    #[prop_or_default]
+    pub color: Option<AttrValue>,
+    #[prop_or(AttrValue::Static("Loading"))]
+    pub text: AttrValue,
+    #[prop_or_default]
+    pub highlight: Option<AttrValue>,
+    #[prop_or(1400)]
+    pub duration: u32,
+}
+
+/// Renders a piece of text followed by three dots that appear one after the
+/// other, see `spux::text::Ellipsis`.
+#[function_component]
+pub fn Ellipsis(props: &TextProps) -> Html {
+    let mut config = EllipsisConfig::new()
+        .text(props.text.as_str())
+        .duration(props.duration);
+    if let Some(color) = &props.color {
+        config = config.color(color.as_str());
+    }
+    render_component(config)
+}
+
+/// Renders a piece of text with a highlight sweeping across it, see
+/// `spux::text::ShimmerText`.
+#[function_component]
+pub fn ShimmerText(props: &ShimmerTextProps) -> Html {
+    let mut config = ShimmerTextConfig::new()
+        .text(props.text.as_str())
+        .duration(props.duration);
+    if let Some(color) = &props.color {
+        config = config.color(color.as_str());
+    }
+    if let Some(highlight) = &props.highlight {
+        config = config.highlight(highlight.as_str());
+    }
+    render_component(config)
+}
+
+/// Renders a piece of text that is typed out character by character behind
+/// a blinking caret, see `spux::text::Typewriter`.
+#[function_component]
+pub fn Typewriter(props: &TextProps) -> Html {
+    let mut config = TypewriterConfig::new()
+        .text(props.text.as_str())
+        .duration(props.duration);
+    if let Some(color) = &props.color {
+        config = config.color(color.as_str());
+    }
+    let (ticks, interval) = config.ticks();
+    let tick = use_tick(ticks, interval);
+    let rendered = render_config(config.clone());
+
+    html! {
+        <>
+            <style>{ Html::from_html_unchecked(rendered.css.into()) }</style>
+            <span class="spux-typewriter" role="status" style={rendered.style}>
+                <span class="spux-visually-hidden">{ props.text.clone() }</span>
+                <span aria-hidden="true">{ config.frame(tick) }</span>
+                <span class="spux-typewriter-caret" aria-hidden="true"></span>
+            </span>
+        </>
+    }
+}
+
+/// Renders a piece of text as scrambled glyphs that resolve into the final
+/// word from left to right, see `spux::text::Scramble`.
+#[function_component]
+pub fn Scramble(props: &TextProps) -> Html {
+    let mut config = ScrambleConfig::new()
+        .text(props.text.as_str())
+        .duration(props.duration);
+    if let Some(color) = &props.color {
+        config = config.color(color.as_str());
+    }
+    let (ticks, interval) = config.ticks();
+    let tick = use_tick(ticks, interval);
+    let rendered = render_config(config.clone());
+
+    html! {
+        <>
+            <style>{ Html::from_html_unchecked(rendered.css.into()) }</style>
+            <span class="spux-scramble" role="status" style={rendered.style}>
+                <span class="spux-visually-hidden">{ props.text.clone() }</span>
+                <span aria-hidden="true">{ config.frame(tick) }</span>
+            </span>
+        </>
+    }
+}
+
+// counts the ticks of a text loader from 0 (and then wraps). effects only run
+// in the browser, so server rendered text always starts from the first tick
+#[hook]
+fn use_tick(ticks: usize, interval: Duration) -> usize {
+    let tick = use_state(|| 0);
+
+    {
+        let tick = tick.setter();
+        use_effect_with((ticks, interval), move |&(ticks, interval)| {
+            let mut current = 0;
+            let handle = Interval::new(interval.as_millis() as u32, move || {
+                current = (current + 1) % ticks;
+                tick.set(current);
+            });
+            move || drop(handle)
+        });
+    }
+
+    *tick
+}
@@ -0,0 +1,83 @@
+/*
+ * Copyright (c) David Lin
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! The [`three_d`](crate::three_d) loaders as Yew function components.
+
+use super::render_component;
+use crate::size::Size;
+use crate::three_d::{CardFlipConfig, CubeConfig, FlipSquareConfig};
+use yew::prelude::*;
+
+/// The props of [`FlipSquare`], which are the same as with Leptos.
+#[derive(Properties, Clone, PartialEq)]
+pub struct FlipSquareProps {
+    #[prop_or_default]
+    pub color: Option<AttrValue>,
+    pub size: Size,
+    #[prop_or_default]
+    pub filled: bool,
+    #[prop_or(AttrValue::Static("Loading"))]
+    pub label: AttrValue,
+}
+
+/// The props of [`Cube`], which are the same as with Leptos.
+#[derive(Properties, Clone, PartialEq)]
+pub struct CubeProps {
+    #[prop_or_default]
+    pub color: Option<AttrValue>,
+    pub size: Size,
+    #[prop_or_default]
+    pub faces: Option<[&'static str; 6]>,
+    #[prop_or(AttrValue::Static("Loading"))]
+    pub label: AttrValue,
+}
+
+/// The props of [`CardFlip`], which are the same as with Leptos.
+#[derive(Properties, Clone, PartialEq)]
+pub struct CardFlipProps {
+    #[prop_or_default]
+    pub color: Option<AttrValue>,
+    pub size: Size,
+    #[prop_or(AttrValue::Static("Loading"))]
+    pub label: AttrValue,
+}
+
+/// Renders a square that flips around its X axis and then around its Y axis
+/// in turn, see `spux::three_d::FlipSquare`.
+#[function_component]
+pub fn FlipSquare(props: &FlipSquareProps) -> Html {
+    let mut config = FlipSquareConfig::new(props.size)
+        .filled(props.filled)
+        .label(props.label.as_str());
+    if let Some(color) = &props.color {
+        config = config.color(color.as_str());
+    }
+    render_component(config)
+}
+
+/// Renders a rotating cube, see `spux::three_d::Cube`.
+#[function_component]
+pub fn Cube(props: &CubeProps) -> Html {
+    let mut config = CubeConfig::new(props.size).label(props.label.as_str());
+    if let Some(color) = &props.color {
+        config = config.color(color.as_str());
+    }
+    if let Some(faces) = props.faces {
+        config = config.faces(faces);
+    }
+    render_component(config)
+}
+
+/// Renders a card that keeps flipping over, see `spux::three_d::CardFlip`.
+#[function_component]
+pub fn CardFlip(props: &CardFlipProps) -> Html {
+    let mut config = CardFlipConfig::new(props.size).label(props.label.as_str());
+    if let Some(color) = &props.color {
+        config = config.color(color.as_str());
+    }
+    render_component(config)
+}